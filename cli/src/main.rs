@@ -0,0 +1,795 @@
+//! `forward-cli`: admin and operator tool for the forward program.
+//!
+//! Every command that writes builds its instruction with `forward-client`,
+//! signs with `--keypair` and sends it to `--url`; with `--dry-run` the signed
+//! transaction is printed (base64) instead of sent.
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::get_associated_token_address;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use forward_client::rpc::{Result, RpcClient};
+use forward_client::{accounts, instructions, pda};
+use forward_client::{
+    CurveBreakpoint, CurveKind, CurveParams, MarketConfig, Outcome, Position, PositionStatus,
+    ResolutionParams, RiskLimits, BASIS_POINTS,
+};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+#[derive(Parser)]
+#[command(name = "forward-cli", version, about = "Operate forward markets")]
+struct Cli {
+    /// RPC endpoint (a local validator by default)
+    #[arg(long, global = true, env = "FORWARD_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Signer and fee payer keypair file [default: ~/.config/solana/id.json]
+    #[arg(long, short = 'k', global = true, env = "FORWARD_KEYPAIR")]
+    keypair: Option<PathBuf>,
+
+    /// Print the signed transaction (base64) instead of sending it
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Initialize the global config (signer becomes admin and all roles)
+    InitGlobalConfig {
+        #[arg(long)]
+        collateral_mint: Pubkey,
+        #[command(flatten)]
+        curve: CurveArgs,
+        /// Timelock for curve parameter changes (seconds)
+        #[arg(long, default_value_t = 86400)]
+        curve_update_delay: i64,
+        /// Bond posted by resolution proposers and disputers (must be positive)
+        #[arg(long)]
+        bond_amount: u64,
+        /// Dispute window after a resolution proposal (seconds)
+        #[arg(long, default_value_t = 7200)]
+        liveness_period: i64,
+        /// Trading fee on notional at open and close (basis points)
+        #[arg(long, default_value_t = 0)]
+        fee_bps: u64,
+    },
+
+    /// Create a market for a Polymarket market ID (admin)
+    CreateMarket {
+        #[arg(long)]
+        market_id: String,
+        /// Unix timestamp when the market resolves
+        #[arg(long)]
+        resolution_timestamp: i64,
+        #[arg(long)]
+        max_total_exposure: u64,
+        /// Maximum long exposure as a share of the total (basis points)
+        #[arg(long, default_value_t = 10000)]
+        max_long_share: u64,
+        /// Maximum short exposure as a share of the total (basis points)
+        #[arg(long, default_value_t = 10000)]
+        max_short_share: u64,
+        /// Use the curve flags as a per-market override instead of the global curve
+        #[arg(long)]
+        override_curve: bool,
+        #[command(flatten)]
+        curve: CurveArgs,
+        /// Per-market trading fee override (basis points)
+        #[arg(long)]
+        fee_bps: Option<u64>,
+    },
+
+    /// Set the global trading fee, or a market's override (admin)
+    SetFee {
+        /// Set the override for this market instead of the global fee
+        #[arg(long)]
+        market_id: Option<String>,
+        /// Fee on notional (basis points)
+        #[arg(long, required_unless_present = "clear")]
+        fee_bps: Option<u64>,
+        /// Remove the market's override so it uses the global fee
+        #[arg(long, requires = "market_id", conflicts_with = "fee_bps")]
+        clear: bool,
+    },
+
+    /// Set the share of the trading fee paid to referrers in a tier (admin)
+    SetReferralTier {
+        #[arg(long)]
+        tier: u8,
+        /// Share of the fee (basis points)
+        #[arg(long)]
+        share_bps: u64,
+    },
+
+    /// Move a referrer to another tier (admin)
+    SetReferrerTier {
+        /// Referrer authority (the wallet that registered)
+        #[arg(long)]
+        referrer: Pubkey,
+        #[arg(long)]
+        tier: u8,
+    },
+
+    /// Set the fee paid per position settled by crank-settle, out of protocol fees (admin)
+    SetCrankFee {
+        /// Collateral amount per settled position (0 disables it, capped at half the position's trading fee)
+        #[arg(long)]
+        amount: u64,
+    },
+
+    /// Withdraw accrued trading fees from the fee vault (admin)
+    WithdrawFees {
+        #[arg(long)]
+        amount: u64,
+        /// Collateral account receiving the fees [default: signer's associated token account]
+        #[arg(long)]
+        treasury: Option<Pubkey>,
+    },
+
+    /// Close an empty resolved or cancelled market and reclaim its rent (admin)
+    ReclaimMarket {
+        #[arg(long)]
+        market_id: String,
+    },
+
+    /// Queue new curve parameters behind the timelock (risk manager)
+    UpdateCurveParams {
+        /// Queue a per-market override instead of changing the global curve
+        #[arg(long)]
+        market_id: Option<String>,
+        /// Queue removal of the market's override, falling back to the global curve
+        #[arg(long, requires = "market_id")]
+        clear_override: bool,
+        #[command(flatten)]
+        curve: CurveArgs,
+    },
+
+    /// Close a market for trading ahead of resolution (risk manager)
+    CloseMarket {
+        #[arg(long)]
+        market_id: String,
+    },
+
+    /// Propose, dispute, finalize or arbitrate a market resolution
+    Resolve {
+        #[command(subcommand)]
+        action: ResolveAction,
+    },
+
+    /// Settle every open position in a resolved market on its owner's behalf (anyone)
+    CrankSettle {
+        #[arg(long)]
+        market_id: String,
+        /// Positions per transaction (bounded by the transaction size limit)
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u64).range(1..=10))]
+        batch_size: u64,
+        /// Don't claim the crank fee
+        #[arg(long)]
+        no_fee: bool,
+    },
+
+    /// Push the current Polymarket price (price keeper)
+    PushPrice {
+        #[arg(long)]
+        market_id: String,
+        /// Price in basis points (5000 = 0.5)
+        #[arg(long)]
+        price: u64,
+        #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+        exponent: i8,
+    },
+
+    /// List all markets
+    ListMarkets,
+
+    /// Show a market's pool state
+    ShowPool {
+        #[arg(long)]
+        market_id: String,
+    },
+
+    /// Show a referrer's tier and accrued fees
+    ShowReferrer {
+        /// Referrer authority (the wallet that registered)
+        #[arg(long)]
+        referrer: Pubkey,
+    },
+
+    /// Show a position by market and position ID, or by address
+    ShowPosition {
+        #[arg(long, required_unless_present = "address")]
+        market_id: Option<String>,
+        #[arg(long, required_unless_present = "address")]
+        position_id: Option<u64>,
+        #[arg(long, conflicts_with_all = ["market_id", "position_id"])]
+        address: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ResolveAction {
+    /// Propose an outcome and post the bond (anyone, after the resolution timestamp)
+    Propose {
+        #[arg(long)]
+        market_id: String,
+        /// yes, no, or a YES payout fraction in basis points
+        #[arg(long, value_parser = parse_outcome)]
+        outcome: Outcome,
+        /// Collateral account paying the bond [default: signer's associated token account]
+        #[arg(long)]
+        collateral_account: Option<Pubkey>,
+    },
+    /// Dispute the pending proposal and post a matching bond
+    Dispute {
+        #[arg(long)]
+        market_id: String,
+        /// Collateral account paying the bond [default: signer's associated token account]
+        #[arg(long)]
+        collateral_account: Option<Pubkey>,
+    },
+    /// Finalize an undisputed proposal after the liveness period
+    Finalize {
+        #[arg(long)]
+        market_id: String,
+    },
+    /// Settle a disputed proposal (resolution keeper)
+    Arbitrate {
+        #[arg(long)]
+        market_id: String,
+        /// yes, no, or a YES payout fraction in basis points
+        #[arg(long, value_parser = parse_outcome)]
+        outcome: Outcome,
+    },
+}
+
+#[derive(Args)]
+struct CurveArgs {
+    /// Price shift at full exposure (basis points)
+    #[arg(long, default_value_t = 1000)]
+    alpha: u64,
+    /// Premium rate at full exposure (basis points)
+    #[arg(long, default_value_t = 500)]
+    beta: u64,
+    /// E_max: maximum absolute net exposure
+    #[arg(long, default_value_t = 1_000_000)]
+    max_exposure: u64,
+    /// Minimum forward price (basis points)
+    #[arg(long, default_value_t = 500)]
+    min_price: u64,
+    /// Maximum forward price (basis points)
+    #[arg(long, default_value_t = 9500)]
+    max_price: u64,
+    /// Curve family
+    #[arg(long, value_enum, default_value_t = CurveKindArg::Linear)]
+    curve: CurveKindArg,
+    /// Steepness k for tanh and exponential curves (basis points, 30000 = 3.0)
+    #[arg(long, default_value_t = 0)]
+    steepness: u64,
+    /// Piecewise breakpoints as exposure:value pairs in basis points, e.g. 5000:2000,10000:10000
+    #[arg(long, value_delimiter = ',', value_parser = parse_breakpoint)]
+    breakpoints: Vec<CurveBreakpoint>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CurveKindArg {
+    Linear,
+    Tanh,
+    Exponential,
+    Piecewise,
+}
+
+impl CurveArgs {
+    fn to_curve_params(&self) -> Result<CurveParams> {
+        if self.breakpoints.len() > CurveParams::MAX_BREAKPOINTS {
+            return Err(format!("at most {} breakpoints are supported", CurveParams::MAX_BREAKPOINTS).into());
+        }
+        let mut breakpoints = [CurveBreakpoint::default(); CurveParams::MAX_BREAKPOINTS];
+        breakpoints[..self.breakpoints.len()].copy_from_slice(&self.breakpoints);
+
+        let curve_params = CurveParams {
+            alpha: self.alpha,
+            beta: self.beta,
+            max_exposure: self.max_exposure,
+            min_price: self.min_price,
+            max_price: self.max_price,
+            kind: match self.curve {
+                CurveKindArg::Linear => CurveKind::Linear,
+                CurveKindArg::Tanh => CurveKind::Tanh,
+                CurveKindArg::Exponential => CurveKind::Exponential,
+                CurveKindArg::Piecewise => CurveKind::Piecewise,
+            },
+            steepness: self.steepness,
+            breakpoint_count: self.breakpoints.len() as u8,
+            breakpoints,
+        };
+        // Same checks the program applies, without paying for a failed transaction
+        curve_params
+            .validate()
+            .map_err(|err| format!("invalid curve parameters: {}", err))?;
+        Ok(curve_params)
+    }
+}
+
+fn parse_breakpoint(value: &str) -> std::result::Result<CurveBreakpoint, String> {
+    let (exposure, shape) = value
+        .split_once(':')
+        .ok_or_else(|| format!("expected exposure:value, got {}", value))?;
+    Ok(CurveBreakpoint {
+        exposure: parse_basis_points(exposure)?,
+        value: parse_basis_points(shape)?,
+    })
+}
+
+fn parse_outcome(value: &str) -> std::result::Result<Outcome, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "yes" => Ok(Outcome::Yes),
+        "no" => Ok(Outcome::No),
+        fraction => fraction
+            .parse::<u64>()
+            .map_err(|_| format!("expected yes, no or a payout fraction in basis points, got {}", value))
+            .and_then(|fraction| {
+                if fraction > BASIS_POINTS {
+                    return Err(format!("payout fraction {} exceeds {} basis points", fraction, BASIS_POINTS));
+                }
+                Ok(Outcome::from_payout_fraction(fraction))
+            }),
+    }
+}
+
+fn parse_basis_points(value: &str) -> std::result::Result<u64, String> {
+    let value = value.trim();
+    let parsed = value.parse::<u64>().map_err(|err| format!("{}: {}", value, err))?;
+    if parsed > BASIS_POINTS {
+        return Err(format!("{} exceeds {} basis points", parsed, BASIS_POINTS));
+    }
+    Ok(parsed)
+}
+
+struct Context {
+    rpc: RpcClient,
+    payer: Keypair,
+    dry_run: bool,
+}
+
+impl Context {
+    fn signer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    fn collateral_mint(&self) -> Result<Pubkey> {
+        let data = self.rpc.get_required_account_data(&pda::global_config(), "global config")?;
+        Ok(accounts::global_config(&data)?.collateral_mint)
+    }
+
+    /// Sign and send `instruction` (or print it with --dry-run)
+    fn send(&self, instruction: Instruction) -> Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.signer()),
+            &[&self.payer],
+            blockhash,
+        );
+
+        if self.dry_run {
+            println!("{}", BASE64.encode(bincode::serialize(&transaction)?));
+            return Ok(());
+        }
+        let signature = self.rpc.send_and_confirm(&transaction)?;
+        println!("Signature: {}", signature);
+        Ok(())
+    }
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let keypair_path = match cli.keypair {
+        Some(path) => path,
+        None => PathBuf::from(std::env::var("HOME")?).join(".config/solana/id.json"),
+    };
+    let payer = read_keypair_file(&keypair_path)
+        .map_err(|err| format!("failed to read keypair {}: {}", keypair_path.display(), err))?;
+    let ctx = Context {
+        rpc: RpcClient::new(cli.url),
+        payer,
+        dry_run: cli.dry_run,
+    };
+
+    match cli.command {
+        Command::InitGlobalConfig {
+            collateral_mint,
+            curve,
+            curve_update_delay,
+            bond_amount,
+            liveness_period,
+            fee_bps,
+        } => ctx.send(instructions::init_global_config(
+            ctx.signer(),
+            collateral_mint,
+            curve.to_curve_params()?,
+            curve_update_delay,
+            ResolutionParams {
+                bond_amount,
+                liveness_period,
+            },
+            fee_bps,
+        )),
+
+        Command::CreateMarket {
+            market_id,
+            resolution_timestamp,
+            max_total_exposure,
+            max_long_share,
+            max_short_share,
+            override_curve,
+            curve,
+            fee_bps,
+        } => {
+            let curve_params = if override_curve {
+                Some(curve.to_curve_params()?)
+            } else {
+                None
+            };
+            ctx.send(instructions::create_market(
+                ctx.signer(),
+                ctx.collateral_mint()?,
+                &market_id,
+                resolution_timestamp,
+                RiskLimits {
+                    max_total_exposure,
+                    max_long_share,
+                    max_short_share,
+                },
+                curve_params,
+                fee_bps,
+            ))?;
+            println!("Market: {}", pda::market(&market_id));
+            Ok(())
+        }
+
+        Command::SetFee {
+            market_id,
+            fee_bps,
+            clear,
+        } => match market_id {
+            Some(market_id) => ctx.send(instructions::update_market_fee_bps(
+                ctx.signer(),
+                pda::market(&market_id),
+                if clear { None } else { fee_bps },
+            )),
+            None => ctx.send(instructions::update_fee_bps(
+                ctx.signer(),
+                fee_bps.ok_or("--fee-bps is required")?,
+            )),
+        },
+
+        Command::SetReferralTier { tier, share_bps } => {
+            ctx.send(instructions::set_referral_tier(ctx.signer(), tier, share_bps))
+        }
+
+        Command::SetReferrerTier { referrer, tier } => {
+            ctx.send(instructions::set_referrer_tier(ctx.signer(), referrer, tier))
+        }
+
+        Command::SetCrankFee { amount } => ctx.send(instructions::update_crank_fee(ctx.signer(), amount)),
+
+        Command::WithdrawFees { amount, treasury } => {
+            let treasury = match treasury {
+                Some(treasury) => treasury,
+                None => get_associated_token_address(&ctx.signer(), &ctx.collateral_mint()?),
+            };
+            ctx.send(instructions::withdraw_fees(ctx.signer(), treasury, amount))
+        }
+
+        Command::ReclaimMarket { market_id } => {
+            // The bond vault is created by the first proposal and closed with the market
+            let market = pda::market(&market_id);
+            let proposed = ctx.rpc.get_account_data(&pda::bond_vault(&market))?.is_some();
+            ctx.send(instructions::close_market(ctx.signer(), market, proposed))
+        }
+
+        Command::UpdateCurveParams {
+            market_id,
+            clear_override,
+            curve,
+        } => {
+            match market_id {
+                Some(market_id) => ctx.send(instructions::update_market_curve_params(
+                    ctx.signer(),
+                    pda::market(&market_id),
+                    if clear_override {
+                        None
+                    } else {
+                        Some(curve.to_curve_params()?)
+                    },
+                )),
+                None => ctx.send(instructions::update_curve_params(ctx.signer(), curve.to_curve_params()?)),
+            }
+        }
+
+        Command::CloseMarket { market_id } => ctx.send(instructions::close_market_for_trading(
+            ctx.signer(),
+            pda::market(&market_id),
+        )),
+
+        Command::Resolve { action } => resolve(&ctx, action),
+
+        Command::CrankSettle {
+            market_id,
+            batch_size,
+            no_fee,
+        } => crank_settle(&ctx, &market_id, batch_size as usize, no_fee),
+
+        Command::PushPrice {
+            market_id,
+            price,
+            exponent,
+        } => ctx.send(instructions::update_price_oracle(
+            ctx.signer(),
+            pda::market(&market_id),
+            price,
+            exponent,
+        )),
+
+        Command::ListMarkets => list_markets(&ctx),
+
+        Command::ShowPool { market_id } => show_pool(&ctx, &market_id),
+
+        Command::ShowReferrer { referrer } => show_referrer(&ctx, &referrer),
+
+        Command::ShowPosition {
+            market_id,
+            position_id,
+            address,
+        } => {
+            let address = match (address, market_id, position_id) {
+                (Some(address), _, _) => address,
+                (None, Some(market_id), Some(position_id)) => pda::position(&pda::market(&market_id), position_id),
+                _ => return Err("pass --address or both --market-id and --position-id".into()),
+            };
+            show_position(&ctx, &address)
+        }
+    }
+}
+
+fn resolve(ctx: &Context, action: ResolveAction) -> Result<()> {
+    match action {
+        ResolveAction::Propose {
+            market_id,
+            outcome,
+            collateral_account,
+        } => {
+            let collateral_mint = ctx.collateral_mint()?;
+            let collateral_account = collateral_account
+                .unwrap_or_else(|| get_associated_token_address(&ctx.signer(), &collateral_mint));
+            ctx.send(instructions::propose_resolution(
+                ctx.signer(),
+                pda::market(&market_id),
+                collateral_mint,
+                collateral_account,
+                outcome,
+            ))
+        }
+        ResolveAction::Dispute {
+            market_id,
+            collateral_account,
+        } => {
+            let collateral_account = match collateral_account {
+                Some(account) => account,
+                None => get_associated_token_address(&ctx.signer(), &ctx.collateral_mint()?),
+            };
+            ctx.send(instructions::dispute_resolution(
+                ctx.signer(),
+                pda::market(&market_id),
+                collateral_account,
+            ))
+        }
+        ResolveAction::Finalize { market_id } => {
+            // The proposer's bond goes back to their associated token account
+            let market = pda::market(&market_id);
+            let oracle = accounts::resolution_oracle(
+                &ctx.rpc
+                    .get_required_account_data(&pda::resolution_oracle(&market), "resolution oracle")?,
+            )?;
+            let proposer_account = get_associated_token_address(&oracle.proposer, &ctx.collateral_mint()?);
+            ctx.send(instructions::finalize_resolution(market, proposer_account))
+        }
+        ResolveAction::Arbitrate { market_id, outcome } => {
+            // Both bonds go to whichever side's outcome is upheld
+            let market = pda::market(&market_id);
+            let oracle = accounts::resolution_oracle(
+                &ctx.rpc
+                    .get_required_account_data(&pda::resolution_oracle(&market), "resolution oracle")?,
+            )?;
+            let winner = if outcome.payout_fraction() == oracle.proposed_payout_fraction {
+                oracle.proposer
+            } else {
+                oracle.disputer
+            };
+            let winner_account = get_associated_token_address(&winner, &ctx.collateral_mint()?);
+            ctx.send(instructions::update_market_resolution(
+                ctx.signer(),
+                market,
+                winner_account,
+                outcome,
+            ))
+        }
+    }
+}
+
+fn crank_settle(ctx: &Context, market_id: &str, batch_size: usize, no_fee: bool) -> Result<()> {
+    let market = pda::market(market_id);
+    let collateral_mint = ctx.collateral_mint()?;
+
+    // Payouts go to each owner's associated token account; owners without one
+    // have to settle themselves
+    let mut positions = Vec::new();
+    for (address, data) in ctx
+        .rpc
+        .get_program_accounts(&forward_client::PROGRAM_ID, Position::DISCRIMINATOR)?
+    {
+        let position = accounts::position(&data)?;
+        if position.market != market || position.status != PositionStatus::Open {
+            continue;
+        }
+        let owner_account = get_associated_token_address(&position.owner, &collateral_mint);
+        if ctx.rpc.get_account_data(&owner_account)?.is_none() {
+            println!("Skipping {}: owner {} has no collateral token account", address, position.owner);
+            continue;
+        }
+        positions.push((address, owner_account));
+    }
+    if positions.is_empty() {
+        println!("No open positions to settle");
+        return Ok(());
+    }
+
+    let cranker_account = (!no_fee).then(|| get_associated_token_address(&ctx.signer(), &collateral_mint));
+    for batch in positions.chunks(batch_size) {
+        println!("Settling {} positions", batch.len());
+        ctx.send(instructions::crank_settle(ctx.signer(), market, batch, cranker_account))?;
+    }
+    Ok(())
+}
+
+fn list_markets(ctx: &Context) -> Result<()> {
+    let markets = ctx
+        .rpc
+        .get_program_accounts(&forward_client::PROGRAM_ID, MarketConfig::DISCRIMINATOR)?;
+    if markets.is_empty() {
+        println!("No markets");
+        return Ok(());
+    }
+    for (address, data) in markets {
+        let market = accounts::market_config(&data)?;
+        println!(
+            "{}  {:<14}  resolves {}  {}",
+            address,
+            format!("{:?}", market.status),
+            market.resolution_timestamp,
+            market.polymarket_market_id
+        );
+    }
+    Ok(())
+}
+
+fn show_pool(ctx: &Context, market_id: &str) -> Result<()> {
+    let market = pda::market(market_id);
+    let pool = accounts::pool_state(
+        &ctx.rpc
+            .get_required_account_data(&pda::pool_state(&market), "pool state")?,
+    )?;
+    let price = accounts::price_oracle(
+        &ctx.rpc
+            .get_required_account_data(&pda::price_oracle(&market), "price oracle")?,
+    )?;
+
+    println!("Market:           {}", market);
+    println!("Pool state:       {}", pda::pool_state(&market));
+    println!("Long exposure:    {}", pool.total_long_exposure);
+    println!("Short exposure:   {}", pool.total_short_exposure);
+    println!("Net exposure:     {}", pool.net_exposure());
+    println!("Pool collateral:  {}", pool.pool_collateral);
+    println!("LP NAV:           {}", pool.lp_nav);
+    println!("Free liquidity:   {}", pool.free_liquidity());
+    println!("Max liability:    {}", pool.max_liability());
+    println!("Positions opened: {}", pool.position_counter);
+    println!("Oracle price:     {} bps (updated {})", price.price, price.timestamp);
+    Ok(())
+}
+
+fn show_referrer(ctx: &Context, authority: &Pubkey) -> Result<()> {
+    let address = pda::referrer(authority);
+    let referrer = accounts::referrer(&ctx.rpc.get_required_account_data(&address, "referrer")?)?;
+    let global_config = accounts::global_config(
+        &ctx.rpc
+            .get_required_account_data(&pda::global_config(), "global config")?,
+    )?;
+    let share_bps = global_config
+        .referral_tiers
+        .get(referrer.tier as usize)
+        .copied()
+        .unwrap_or_default();
+
+    println!("Referrer:  {}", address);
+    println!("Authority: {}", referrer.authority);
+    println!("Tier:      {} ({} bps of the fee)", referrer.tier, share_bps);
+    println!("Accrued:   {}", referrer.accrued);
+    println!("Claimed:   {}", referrer.claimed);
+    println!("Claimable: {}", referrer.claimable()?);
+    Ok(())
+}
+
+fn show_position(ctx: &Context, address: &Pubkey) -> Result<()> {
+    let position = accounts::position(&ctx.rpc.get_required_account_data(address, "position")?)?;
+
+    println!("Position:          {}", address);
+    println!("Owner:             {}", position.owner);
+    println!("Market:            {}", position.market);
+    println!("Direction:         {:?}", position.direction);
+    println!("Size:              {}", position.size);
+    println!("Forward price:     {} bps", position.forward_price);
+    println!("Collateral locked: {}", position.collateral_locked);
+    println!("Premium paid:      {}", position.premium_paid);
+    println!("Fee paid:          {}", position.fee_paid);
+    println!("Status:            {:?}", position.status);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(parse_outcome("yes"), Ok(Outcome::Yes));
+        assert_eq!(parse_outcome("NO"), Ok(Outcome::No));
+        assert_eq!(parse_outcome("5000"), Ok(Outcome::Split(5000)));
+        assert_eq!(parse_outcome("10000"), Ok(Outcome::Yes));
+        assert_eq!(parse_outcome("0"), Ok(Outcome::No));
+    }
+
+    #[test]
+    fn rejects_out_of_range_and_malformed_outcomes() {
+        assert!(parse_outcome("10001").is_err());
+        assert!(parse_outcome("25000").is_err());
+        assert!(parse_outcome("-1").is_err());
+        assert!(parse_outcome("maybe").is_err());
+        assert!(parse_outcome("").is_err());
+    }
+
+    #[test]
+    fn parses_breakpoints() {
+        let point = parse_breakpoint("5000:2000").unwrap();
+        assert_eq!((point.exposure, point.value), (5000, 2000));
+
+        let point = parse_breakpoint(" 10000 : 10000 ").unwrap();
+        assert_eq!((point.exposure, point.value), (10000, 10000));
+    }
+
+    #[test]
+    fn rejects_out_of_range_and_malformed_breakpoints() {
+        assert!(parse_breakpoint("10001:5000").is_err());
+        assert!(parse_breakpoint("5000:10001").is_err());
+        assert!(parse_breakpoint("5000").is_err());
+        assert!(parse_breakpoint("5000:").is_err());
+        assert!(parse_breakpoint("a:b").is_err());
+        assert!(parse_breakpoint("5000,2000").is_err());
+    }
+}
@@ -0,0 +1,50 @@
+//! Decoders for the program's accounts.
+//!
+//! Each decoder checks the Anchor discriminator, so passing data from the
+//! wrong account type fails instead of producing garbage.
+
+use anchor_lang::prelude::*;
+use anchor_lang::AccountDeserialize;
+use forward::oracle::{PriceOracle, ResolutionOracle};
+use forward::state::{GlobalConfig, MarketConfig, Multisig, PoolState, Position, Proposal, Referrer};
+
+/// Decode any program account from its raw data
+pub fn decode<T: AccountDeserialize>(mut data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut data)
+}
+
+pub fn global_config(data: &[u8]) -> Result<GlobalConfig> {
+    decode(data)
+}
+
+pub fn market_config(data: &[u8]) -> Result<MarketConfig> {
+    decode(data)
+}
+
+pub fn pool_state(data: &[u8]) -> Result<PoolState> {
+    decode(data)
+}
+
+pub fn position(data: &[u8]) -> Result<Position> {
+    decode(data)
+}
+
+pub fn referrer(data: &[u8]) -> Result<Referrer> {
+    decode(data)
+}
+
+pub fn price_oracle(data: &[u8]) -> Result<PriceOracle> {
+    decode(data)
+}
+
+pub fn resolution_oracle(data: &[u8]) -> Result<ResolutionOracle> {
+    decode(data)
+}
+
+pub fn multisig(data: &[u8]) -> Result<Multisig> {
+    decode(data)
+}
+
+pub fn proposal(data: &[u8]) -> Result<Proposal> {
+    decode(data)
+}
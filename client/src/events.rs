@@ -0,0 +1,136 @@
+//! Decoders for the program's events.
+//!
+//! Events are emitted with `emit_cpi!`, so they arrive as the data of an inner
+//! instruction the program invokes on itself rather than as log lines. Pass
+//! that instruction data to [`decode`].
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use forward::events::*;
+
+#[derive(Clone)]
+pub enum Event {
+    GlobalConfigInitialized(GlobalConfigInitialized),
+    MarketCreated(MarketCreated),
+    CurveParamsUpdated(CurveParamsUpdated),
+    MarketClosed(MarketClosed),
+    PositionOpened(PositionOpened),
+    PositionIncreased(PositionIncreased),
+    PositionClosed(PositionClosed),
+    PositionSettled(PositionSettled),
+    PositionRefunded(PositionRefunded),
+    CrankSettled(CrankSettled),
+    PriceUpdated(PriceUpdated),
+    MarketResolved(MarketResolved),
+    MarketCancelled(MarketCancelled),
+    MarketReclaimed(MarketReclaimed),
+    ResolutionProposed(ResolutionProposed),
+    ResolutionDisputed(ResolutionDisputed),
+    LiquidityDeposited(LiquidityDeposited),
+    LiquidityWithdrawn(LiquidityWithdrawn),
+    FeesWithdrawn(FeesWithdrawn),
+    ReferralFeesClaimed(ReferralFeesClaimed),
+    CurveParamsUpdateCancelled(CurveParamsUpdateCancelled),
+    CurveUpdateDelayUpdated(CurveUpdateDelayUpdated),
+    PositionAccountClosed(PositionAccountClosed),
+    FeeUpdated(FeeUpdated),
+    CrankFeeUpdated(CrankFeeUpdated),
+    ReferralTierUpdated(ReferralTierUpdated),
+    ReferrerTierUpdated(ReferrerTierUpdated),
+    RoleUpdated(RoleUpdated),
+    AdminProposed(AdminProposed),
+    AdminTransferred(AdminTransferred),
+    ResolutionParamsUpdated(ResolutionParamsUpdated),
+    ReferrerRegistered(ReferrerRegistered),
+    MultisigCreated(MultisigCreated),
+    MultisigUpdated(MultisigUpdated),
+    ProposalCreated(ProposalCreated),
+    ProposalApproved(ProposalApproved),
+    ProposalExecuted(ProposalExecuted),
+}
+
+/// Decode the data of an event self-CPI, or `None` if it is not a forward event
+pub fn decode(data: &[u8]) -> Option<Event> {
+    let data = data.strip_prefix(EVENT_IX_TAG_LE)?;
+    Some(if let Some(data) = data.strip_prefix(GlobalConfigInitialized::DISCRIMINATOR) {
+        Event::GlobalConfigInitialized(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(MarketCreated::DISCRIMINATOR) {
+        Event::MarketCreated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(CurveParamsUpdated::DISCRIMINATOR) {
+        Event::CurveParamsUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(MarketClosed::DISCRIMINATOR) {
+        Event::MarketClosed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(PositionOpened::DISCRIMINATOR) {
+        Event::PositionOpened(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(PositionIncreased::DISCRIMINATOR) {
+        Event::PositionIncreased(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(PositionClosed::DISCRIMINATOR) {
+        Event::PositionClosed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(PositionSettled::DISCRIMINATOR) {
+        Event::PositionSettled(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(PositionRefunded::DISCRIMINATOR) {
+        Event::PositionRefunded(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(CrankSettled::DISCRIMINATOR) {
+        Event::CrankSettled(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(PriceUpdated::DISCRIMINATOR) {
+        Event::PriceUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(MarketResolved::DISCRIMINATOR) {
+        Event::MarketResolved(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(MarketCancelled::DISCRIMINATOR) {
+        Event::MarketCancelled(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(MarketReclaimed::DISCRIMINATOR) {
+        Event::MarketReclaimed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ResolutionProposed::DISCRIMINATOR) {
+        Event::ResolutionProposed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ResolutionDisputed::DISCRIMINATOR) {
+        Event::ResolutionDisputed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(LiquidityDeposited::DISCRIMINATOR) {
+        Event::LiquidityDeposited(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(LiquidityWithdrawn::DISCRIMINATOR) {
+        Event::LiquidityWithdrawn(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(FeesWithdrawn::DISCRIMINATOR) {
+        Event::FeesWithdrawn(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ReferralFeesClaimed::DISCRIMINATOR) {
+        Event::ReferralFeesClaimed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(CurveParamsUpdateCancelled::DISCRIMINATOR) {
+        Event::CurveParamsUpdateCancelled(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(CurveUpdateDelayUpdated::DISCRIMINATOR) {
+        Event::CurveUpdateDelayUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(PositionAccountClosed::DISCRIMINATOR) {
+        Event::PositionAccountClosed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(FeeUpdated::DISCRIMINATOR) {
+        Event::FeeUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(CrankFeeUpdated::DISCRIMINATOR) {
+        Event::CrankFeeUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ReferralTierUpdated::DISCRIMINATOR) {
+        Event::ReferralTierUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ReferrerTierUpdated::DISCRIMINATOR) {
+        Event::ReferrerTierUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(RoleUpdated::DISCRIMINATOR) {
+        Event::RoleUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(AdminProposed::DISCRIMINATOR) {
+        Event::AdminProposed(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(AdminTransferred::DISCRIMINATOR) {
+        Event::AdminTransferred(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ResolutionParamsUpdated::DISCRIMINATOR) {
+        Event::ResolutionParamsUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ReferrerRegistered::DISCRIMINATOR) {
+        Event::ReferrerRegistered(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(MultisigCreated::DISCRIMINATOR) {
+        Event::MultisigCreated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(MultisigUpdated::DISCRIMINATOR) {
+        Event::MultisigUpdated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ProposalCreated::DISCRIMINATOR) {
+        Event::ProposalCreated(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ProposalApproved::DISCRIMINATOR) {
+        Event::ProposalApproved(deserialize(data)?)
+    } else if let Some(data) = data.strip_prefix(ProposalExecuted::DISCRIMINATOR) {
+        Event::ProposalExecuted(deserialize(data)?)
+    } else {
+        return None;
+    })
+}
+
+fn deserialize<T: AnchorDeserialize>(mut data: &[u8]) -> Option<T> {
+    T::deserialize(&mut data).ok()
+}
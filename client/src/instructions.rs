@@ -0,0 +1,984 @@
+//! Instruction builders for every instruction in the forward program.
+//!
+//! Builders derive all PDAs themselves; callers only pass signers, token
+//! accounts they own and instruction arguments. Markets are identified by their
+//! `MarketConfig` address (see [`crate::pda::market`]).
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::token;
+use forward::state::{
+    CurveParams, Direction, Outcome, ProposalAccount, ResolutionParams, RiskLimits, Role,
+};
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: forward::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Optional order protections for `open_position` and `increase_position`
+#[derive(Clone, Debug, Default)]
+pub struct OrderLimits {
+    pub slippage_tolerance: Option<u64>,  // Max |K - p| / p (basis points)
+    pub limit_forward_price: Option<u64>, // Max K for longs, min K for shorts (basis points)
+    pub max_premium: Option<i64>,         // Max premium paid (negative: min received)
+    pub expires_at: Option<i64>,          // Unix timestamp after which the order is rejected
+}
+
+// ---------------------------------------------------------------------------
+// Admin
+// ---------------------------------------------------------------------------
+
+pub fn init_global_config(
+    admin: Pubkey,
+    collateral_mint: Pubkey,
+    curve_params: CurveParams,
+    curve_update_delay: i64,
+    resolution_params: ResolutionParams,
+    fee_bps: u64,
+) -> Instruction {
+    build(
+        forward::accounts::InitGlobalConfig {
+            admin,
+            global_config: pda::global_config(),
+            collateral_mint,
+            fee_vault: pda::fee_vault(),
+            fee_ledger: pda::fee_ledger(),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::InitGlobalConfig {
+            curve_params,
+            curve_update_delay,
+            resolution_params,
+            fee_bps,
+        },
+    )
+}
+
+pub fn create_market(
+    admin: Pubkey,
+    collateral_mint: Pubkey,
+    polymarket_market_id: &str,
+    resolution_timestamp: i64,
+    risk_limits: RiskLimits,
+    curve_params: Option<CurveParams>,
+    fee_bps: Option<u64>,
+) -> Instruction {
+    let market = pda::market(polymarket_market_id);
+    build(
+        forward::accounts::CreateMarket {
+            admin,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            price_oracle: pda::price_oracle(&market),
+            resolution_oracle: pda::resolution_oracle(&market),
+            mint: collateral_mint,
+            collateral_vault: pda::collateral_vault(&market),
+            lp_mint: pda::lp_mint(&market),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CreateMarket {
+            polymarket_market_id: polymarket_market_id.to_string(),
+            resolution_timestamp,
+            risk_limits,
+            curve_params,
+            fee_bps,
+        },
+    )
+}
+
+pub fn cancel_market(admin: Pubkey, market: Pubkey) -> Instruction {
+    build(
+        forward::accounts::CancelMarket {
+            authority: admin,
+            global_config: pda::global_config(),
+            market_config: market,
+            resolution_oracle: pda::resolution_oracle(&market),
+            bond_vault: None,
+            proposer_collateral_account: None,
+            disputer_collateral_account: None,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CancelMarket {},
+    )
+}
+
+/// Void a market whose proposed outcome is still in its dispute window,
+/// returning the proposer's bond
+pub fn cancel_proposed_market(
+    resolution_keeper: Pubkey,
+    market: Pubkey,
+    proposer_collateral_account: Pubkey,
+) -> Instruction {
+    build(
+        forward::accounts::CancelMarket {
+            authority: resolution_keeper,
+            global_config: pda::global_config(),
+            market_config: market,
+            resolution_oracle: pda::resolution_oracle(&market),
+            bond_vault: Some(pda::bond_vault(&market)),
+            proposer_collateral_account: Some(proposer_collateral_account),
+            disputer_collateral_account: None,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CancelMarket {},
+    )
+}
+
+/// Void a market whose resolution is disputed, returning each side's bond
+pub fn cancel_disputed_market(
+    resolution_keeper: Pubkey,
+    market: Pubkey,
+    proposer_collateral_account: Pubkey,
+    disputer_collateral_account: Pubkey,
+) -> Instruction {
+    build(
+        forward::accounts::CancelMarket {
+            authority: resolution_keeper,
+            global_config: pda::global_config(),
+            market_config: market,
+            resolution_oracle: pda::resolution_oracle(&market),
+            bond_vault: Some(pda::bond_vault(&market)),
+            proposer_collateral_account: Some(proposer_collateral_account),
+            disputer_collateral_account: Some(disputer_collateral_account),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CancelMarket {},
+    )
+}
+
+/// Close a resolved or cancelled market once every position is settled and
+/// every LP share burned; any dust left in the vaults goes to the fee vault.
+/// `proposed` passes the bond vault, which exists once an outcome was proposed.
+pub fn close_market(admin: Pubkey, market: Pubkey, proposed: bool) -> Instruction {
+    build(
+        forward::accounts::CloseMarket {
+            admin,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            price_oracle: pda::price_oracle(&market),
+            resolution_oracle: pda::resolution_oracle(&market),
+            collateral_vault: pda::collateral_vault(&market),
+            bond_vault: proposed.then(|| pda::bond_vault(&market)),
+            lp_mint: pda::lp_mint(&market),
+            fee_vault: pda::fee_vault(),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CloseMarket {},
+    )
+}
+
+pub fn cancel_curve_params_update(admin: Pubkey) -> Instruction {
+    build(
+        forward::accounts::CancelCurveParamsUpdate {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CancelCurveParamsUpdate {},
+    )
+}
+
+pub fn cancel_market_curve_params_update(admin: Pubkey, market: Pubkey) -> Instruction {
+    build(
+        forward::accounts::CancelMarketCurveParamsUpdate {
+            admin,
+            global_config: pda::global_config(),
+            market_config: market,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CancelMarketCurveParamsUpdate {},
+    )
+}
+
+pub fn update_curve_update_delay(admin: Pubkey, curve_update_delay: i64) -> Instruction {
+    build(
+        forward::accounts::UpdateCurveUpdateDelay {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateCurveUpdateDelay { curve_update_delay },
+    )
+}
+
+pub fn update_resolution_params(admin: Pubkey, resolution_params: ResolutionParams) -> Instruction {
+    build(
+        forward::accounts::UpdateResolutionParams {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateResolutionParams { resolution_params },
+    )
+}
+
+pub fn update_fee_bps(admin: Pubkey, fee_bps: u64) -> Instruction {
+    build(
+        forward::accounts::UpdateFeeBps {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateFeeBps { fee_bps },
+    )
+}
+
+pub fn update_market_fee_bps(admin: Pubkey, market: Pubkey, fee_bps: Option<u64>) -> Instruction {
+    build(
+        forward::accounts::UpdateMarketFeeBps {
+            admin,
+            global_config: pda::global_config(),
+            market_config: market,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateMarketFeeBps { fee_bps },
+    )
+}
+
+/// Withdraw `amount` of accrued fees to `treasury`, a collateral token account
+pub fn withdraw_fees(admin: Pubkey, treasury: Pubkey, amount: u64) -> Instruction {
+    build(
+        forward::accounts::WithdrawFees {
+            admin,
+            global_config: pda::global_config(),
+            fee_vault: pda::fee_vault(),
+            fee_ledger: pda::fee_ledger(),
+            treasury,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::WithdrawFees { amount },
+    )
+}
+
+/// Set the fee paid from the fee vault per position settled by `crank_settle`
+pub fn update_crank_fee(admin: Pubkey, crank_fee: u64) -> Instruction {
+    build(
+        forward::accounts::UpdateCrankFee {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateCrankFee { crank_fee },
+    )
+}
+
+pub fn set_referral_tier(admin: Pubkey, tier: u8, share_bps: u64) -> Instruction {
+    build(
+        forward::accounts::SetReferralTier {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::SetReferralTier { tier, share_bps },
+    )
+}
+
+/// Move the referrer owned by `referrer_authority` to `tier`
+pub fn set_referrer_tier(admin: Pubkey, referrer_authority: Pubkey, tier: u8) -> Instruction {
+    build(
+        forward::accounts::SetReferrerTier {
+            admin,
+            global_config: pda::global_config(),
+            referrer: pda::referrer(&referrer_authority),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::SetReferrerTier { tier },
+    )
+}
+
+pub fn set_role(admin: Pubkey, role: Role, authority: Pubkey) -> Instruction {
+    build(
+        forward::accounts::SetRole {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::SetRole { role, authority },
+    )
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Option<Pubkey>) -> Instruction {
+    build(
+        forward::accounts::ProposeAdmin {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::ProposeAdmin { new_admin },
+    )
+}
+
+pub fn accept_admin(new_admin: Pubkey) -> Instruction {
+    build(
+        forward::accounts::AcceptAdmin {
+            new_admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::AcceptAdmin {},
+    )
+}
+
+pub fn renounce_admin(admin: Pubkey) -> Instruction {
+    build(
+        forward::accounts::RenounceAdmin {
+            admin,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::RenounceAdmin {},
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Multisig
+// ---------------------------------------------------------------------------
+
+pub fn create_multisig(admin: Pubkey, members: Vec<Pubkey>, threshold: u8) -> Instruction {
+    build(
+        forward::accounts::CreateMultisig {
+            admin,
+            global_config: pda::global_config(),
+            multisig: pda::multisig(),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CreateMultisig { members, threshold },
+    )
+}
+
+/// Propose `proposed` (an instruction built by this module, signed by the
+/// multisig signer PDA) as proposal number `index` (the multisig's current
+/// `proposal_count`)
+pub fn create_proposal(proposer: Pubkey, index: u64, proposed: &Instruction) -> Instruction {
+    let multisig = pda::multisig();
+    build(
+        forward::accounts::CreateProposal {
+            proposer,
+            multisig,
+            proposal: pda::proposal(&multisig, index),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CreateProposal {
+            accounts: proposed
+                .accounts
+                .iter()
+                .map(|meta| ProposalAccount {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: proposed.data.clone(),
+        },
+    )
+}
+
+pub fn approve_proposal(member: Pubkey, proposal: Pubkey) -> Instruction {
+    build(
+        forward::accounts::ApproveProposal {
+            member,
+            multisig: pda::multisig(),
+            proposal,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::ApproveProposal {},
+    )
+}
+
+/// Replace the multisig members and threshold; must be executed through a
+/// proposal, and invalidates every other pending proposal
+pub fn update_multisig(members: Vec<Pubkey>, threshold: u8) -> Instruction {
+    let multisig = pda::multisig();
+    build(
+        forward::accounts::UpdateMultisig {
+            multisig,
+            multisig_signer: pda::multisig_signer(&multisig),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateMultisig { members, threshold },
+    )
+}
+
+/// Execute `proposal`, passing the accounts of the proposed instruction (as
+/// stored in the proposal) and the program itself as remaining accounts
+pub fn execute_proposal(proposal: Pubkey, proposed_accounts: &[ProposalAccount]) -> Instruction {
+    let multisig = pda::multisig();
+    let mut instruction = build(
+        forward::accounts::ExecuteProposal {
+            multisig,
+            proposal,
+            multisig_signer: pda::multisig_signer(&multisig),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::ExecuteProposal {},
+    );
+    // The signer PDA signs inside the CPI, not at the transaction level
+    instruction
+        .accounts
+        .extend(proposed_accounts.iter().map(|account| {
+            if account.is_writable {
+                AccountMeta::new(account.pubkey, false)
+            } else {
+                AccountMeta::new_readonly(account.pubkey, false)
+            }
+        }));
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(forward::ID, false));
+    instruction
+}
+
+// ---------------------------------------------------------------------------
+// Risk manager
+// ---------------------------------------------------------------------------
+
+pub fn update_curve_params(risk_manager: Pubkey, curve_params: CurveParams) -> Instruction {
+    build(
+        forward::accounts::UpdateCurveParams {
+            risk_manager,
+            global_config: pda::global_config(),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateCurveParams { curve_params },
+    )
+}
+
+/// Queue a per-market curve override, or with `None` its removal
+pub fn update_market_curve_params(
+    risk_manager: Pubkey,
+    market: Pubkey,
+    curve_params: Option<CurveParams>,
+) -> Instruction {
+    build(
+        forward::accounts::UpdateMarketCurveParams {
+            risk_manager,
+            global_config: pda::global_config(),
+            market_config: market,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateMarketCurveParams { curve_params },
+    )
+}
+
+pub fn close_market_for_trading(risk_manager: Pubkey, market: Pubkey) -> Instruction {
+    build(
+        forward::accounts::CloseMarketForTrading {
+            risk_manager,
+            global_config: pda::global_config(),
+            market_config: market,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CloseMarketForTrading {},
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Price keeper
+// ---------------------------------------------------------------------------
+
+pub fn update_price_oracle(price_keeper: Pubkey, market: Pubkey, price: u64, exponent: i8) -> Instruction {
+    build(
+        forward::accounts::UpdatePriceOracle {
+            price_keeper,
+            global_config: pda::global_config(),
+            market_config: market,
+            price_oracle: pda::price_oracle(&market),
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdatePriceOracle { price, exponent },
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Users
+// ---------------------------------------------------------------------------
+
+/// Open a position; `position_id` is the pool's current `position_counter`
+///
+/// `referrer` is the referrer's authority (not its PDA), credited with its
+/// tier's share of the trading fee.
+#[allow(clippy::too_many_arguments)]
+pub fn open_position(
+    user: Pubkey,
+    market: Pubkey,
+    position_id: u64,
+    user_collateral_account: Pubkey,
+    direction: Direction,
+    size: u64,
+    limits: OrderLimits,
+    referrer: Option<Pubkey>,
+) -> Instruction {
+    build(
+        forward::accounts::OpenPosition {
+            user,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            price_oracle: pda::price_oracle(&market),
+            collateral_vault: pda::collateral_vault(&market),
+            fee_vault: pda::fee_vault(),
+            user_collateral_account,
+            position: pda::position(&market, position_id),
+            referrer: referrer.map(|authority| pda::referrer(&authority)),
+            fee_ledger: referrer.map(|_| pda::fee_ledger()),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::OpenPosition {
+            direction,
+            size,
+            slippage_tolerance: limits.slippage_tolerance,
+            limit_forward_price: limits.limit_forward_price,
+            max_premium: limits.max_premium,
+            expires_at: limits.expires_at,
+        },
+    )
+}
+
+pub fn increase_position(
+    user: Pubkey,
+    market: Pubkey,
+    position: Pubkey,
+    user_collateral_account: Pubkey,
+    size: u64,
+    limits: OrderLimits,
+    referrer: Option<Pubkey>,
+) -> Instruction {
+    build(
+        forward::accounts::IncreasePosition {
+            user,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            price_oracle: pda::price_oracle(&market),
+            collateral_vault: pda::collateral_vault(&market),
+            fee_vault: pda::fee_vault(),
+            user_collateral_account,
+            position,
+            referrer: referrer.map(|authority| pda::referrer(&authority)),
+            fee_ledger: referrer.map(|_| pda::fee_ledger()),
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::IncreasePosition {
+            size,
+            slippage_tolerance: limits.slippage_tolerance,
+            limit_forward_price: limits.limit_forward_price,
+            max_premium: limits.max_premium,
+            expires_at: limits.expires_at,
+        },
+    )
+}
+
+pub fn quote_open(market: Pubkey, direction: Direction, size: u64) -> Instruction {
+    build(
+        forward::accounts::QuoteOpen {
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            collateral_vault: pda::collateral_vault(&market),
+            price_oracle: pda::price_oracle(&market),
+        },
+        forward::instruction::QuoteOpen { direction, size },
+    )
+}
+
+pub fn quote_close(market: Pubkey, position: Pubkey, size: Option<u64>) -> Instruction {
+    build(
+        forward::accounts::QuoteClose {
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            price_oracle: pda::price_oracle(&market),
+            position,
+        },
+        forward::instruction::QuoteClose { size },
+    )
+}
+
+pub fn close_position(
+    user: Pubkey,
+    market: Pubkey,
+    position: Pubkey,
+    user_collateral_account: Pubkey,
+    min_payout: u64,
+    expires_at: Option<i64>,
+) -> Instruction {
+    build(
+        forward::accounts::ClosePosition {
+            user,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            price_oracle: pda::price_oracle(&market),
+            position,
+            collateral_vault: pda::collateral_vault(&market),
+            fee_vault: pda::fee_vault(),
+            user_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::ClosePosition {
+            min_payout,
+            expires_at,
+        },
+    )
+}
+
+pub fn reduce_position(
+    user: Pubkey,
+    market: Pubkey,
+    position: Pubkey,
+    user_collateral_account: Pubkey,
+    size: u64,
+    min_payout: u64,
+    expires_at: Option<i64>,
+) -> Instruction {
+    build(
+        forward::accounts::ReducePosition {
+            user,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            price_oracle: pda::price_oracle(&market),
+            position,
+            collateral_vault: pda::collateral_vault(&market),
+            fee_vault: pda::fee_vault(),
+            user_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::ReducePosition {
+            size,
+            min_payout,
+            expires_at,
+        },
+    )
+}
+
+/// Settle a resolved position; `close` also closes the account and returns its rent
+pub fn settle_position(
+    user: Pubkey,
+    market: Pubkey,
+    position: Pubkey,
+    user_collateral_account: Pubkey,
+    close: bool,
+) -> Instruction {
+    build(
+        forward::accounts::SettlePosition {
+            user,
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            resolution_oracle: pda::resolution_oracle(&market),
+            position,
+            collateral_vault: pda::collateral_vault(&market),
+            user_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::SettlePosition { close },
+    )
+}
+
+pub fn refund_position(
+    user: Pubkey,
+    market: Pubkey,
+    position: Pubkey,
+    user_collateral_account: Pubkey,
+) -> Instruction {
+    build(
+        forward::accounts::RefundPosition {
+            user,
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            position,
+            collateral_vault: pda::collateral_vault(&market),
+            user_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::RefundPosition {},
+    )
+}
+
+/// Close a settled, closed or refunded position and return its rent to `user`
+pub fn close_settled_position(user: Pubkey, position: Pubkey) -> Instruction {
+    build(
+        forward::accounts::CloseSettledPosition {
+            user,
+            position,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CloseSettledPosition {},
+    )
+}
+
+/// Settle `positions`, given as `(position, owner_collateral_account)` pairs,
+/// on behalf of their owners
+///
+/// The crank fee is paid to `cranker_collateral_account` if set. Batch size is
+/// bounded by the transaction size and compute limit.
+pub fn crank_settle(
+    cranker: Pubkey,
+    market: Pubkey,
+    positions: &[(Pubkey, Pubkey)],
+    cranker_collateral_account: Option<Pubkey>,
+) -> Instruction {
+    let mut instruction = build(
+        forward::accounts::CrankSettle {
+            cranker,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            resolution_oracle: pda::resolution_oracle(&market),
+            collateral_vault: pda::collateral_vault(&market),
+            fee_vault: pda::fee_vault(),
+            fee_ledger: pda::fee_ledger(),
+            cranker_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::CrankSettle {},
+    );
+    for (position, owner_collateral_account) in positions {
+        instruction.accounts.push(AccountMeta::new(*position, false));
+        instruction
+            .accounts
+            .push(AccountMeta::new(*owner_collateral_account, false));
+    }
+    instruction
+}
+
+// ---------------------------------------------------------------------------
+// Referrers
+// ---------------------------------------------------------------------------
+
+pub fn register_referrer(authority: Pubkey) -> Instruction {
+    build(
+        forward::accounts::RegisterReferrer {
+            authority,
+            referrer: pda::referrer(&authority),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::RegisterReferrer {},
+    )
+}
+
+/// Claim accrued referral fees into `destination`, a collateral token account
+pub fn claim_referral_fees(authority: Pubkey, destination: Pubkey) -> Instruction {
+    build(
+        forward::accounts::ClaimReferralFees {
+            authority,
+            global_config: pda::global_config(),
+            fee_ledger: pda::fee_ledger(),
+            referrer: pda::referrer(&authority),
+            fee_vault: pda::fee_vault(),
+            destination,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::ClaimReferralFees {},
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Liquidity providers
+// ---------------------------------------------------------------------------
+
+pub fn deposit_liquidity(
+    user: Pubkey,
+    market: Pubkey,
+    user_collateral_account: Pubkey,
+    user_lp_account: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        forward::accounts::DepositLiquidity {
+            user,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            collateral_vault: pda::collateral_vault(&market),
+            fee_vault: pda::fee_vault(),
+            lp_mint: pda::lp_mint(&market),
+            user_collateral_account,
+            user_lp_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::DepositLiquidity { amount },
+    )
+}
+
+pub fn withdraw_liquidity(
+    user: Pubkey,
+    market: Pubkey,
+    user_collateral_account: Pubkey,
+    user_lp_account: Pubkey,
+    shares: u64,
+) -> Instruction {
+    build(
+        forward::accounts::WithdrawLiquidity {
+            user,
+            global_config: pda::global_config(),
+            market_config: market,
+            pool_state: pda::pool_state(&market),
+            collateral_vault: pda::collateral_vault(&market),
+            lp_mint: pda::lp_mint(&market),
+            user_collateral_account,
+            user_lp_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::WithdrawLiquidity { shares },
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Resolution
+// ---------------------------------------------------------------------------
+
+pub fn propose_resolution(
+    proposer: Pubkey,
+    market: Pubkey,
+    collateral_mint: Pubkey,
+    proposer_collateral_account: Pubkey,
+    outcome: Outcome,
+) -> Instruction {
+    build(
+        forward::accounts::ProposeResolution {
+            proposer,
+            global_config: pda::global_config(),
+            market_config: market,
+            resolution_oracle: pda::resolution_oracle(&market),
+            collateral_mint,
+            bond_vault: pda::bond_vault(&market),
+            proposer_collateral_account,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::ProposeResolution { outcome },
+    )
+}
+
+pub fn dispute_resolution(
+    disputer: Pubkey,
+    market: Pubkey,
+    disputer_collateral_account: Pubkey,
+) -> Instruction {
+    build(
+        forward::accounts::DisputeResolution {
+            disputer,
+            market_config: market,
+            resolution_oracle: pda::resolution_oracle(&market),
+            bond_vault: pda::bond_vault(&market),
+            disputer_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::DisputeResolution {},
+    )
+}
+
+pub fn finalize_resolution(market: Pubkey, proposer_collateral_account: Pubkey) -> Instruction {
+    build(
+        forward::accounts::FinalizeResolution {
+            market_config: market,
+            resolution_oracle: pda::resolution_oracle(&market),
+            bond_vault: pda::bond_vault(&market),
+            proposer_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::FinalizeResolution {},
+    )
+}
+
+pub fn update_market_resolution(
+    resolution_keeper: Pubkey,
+    market: Pubkey,
+    winner_collateral_account: Pubkey,
+    outcome: Outcome,
+) -> Instruction {
+    build(
+        forward::accounts::UpdateMarketResolution {
+            resolution_keeper,
+            global_config: pda::global_config(),
+            market_config: market,
+            resolution_oracle: pda::resolution_oracle(&market),
+            bond_vault: pda::bond_vault(&market),
+            winner_collateral_account,
+            token_program: token::ID,
+            event_authority: pda::event_authority(),
+            program: forward::ID,
+        },
+        forward::instruction::UpdateMarketResolution { outcome },
+    )
+}
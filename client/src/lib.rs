@@ -0,0 +1,25 @@
+//! Rust client for the forward program.
+//!
+//! - [`pda`]: addresses of every program-derived account
+//! - [`instructions`]: builders for every instruction in the program
+//! - [`accounts`]: decoders for on-chain account data
+//! - [`events`]: decoders for the events the program emits
+//! - [`quote`]: off-chain trade previews using the program's own math
+//! - `rpc` (feature `rpc`): a minimal blocking JSON-RPC client
+
+pub mod accounts;
+pub mod events;
+pub mod instructions;
+pub mod pda;
+pub mod quote;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+pub use forward::ID as PROGRAM_ID;
+pub use forward::math::BASIS_POINTS;
+pub use forward::oracle::{PriceOracle, ResolutionOracle, ResolutionStatus, MAX_PRICE_AGE};
+pub use forward::state::{
+    CloseQuote, CurveBreakpoint, CurveKind, CurveParams, Direction, GlobalConfig, MarketConfig,
+    MarketStatus, Multisig, Outcome, PoolState, Position, PositionStatus, Proposal,
+    ProposalAccount, Quote, Referrer, ResolutionParams, RiskLimits, Role,
+};
@@ -0,0 +1,89 @@
+//! Program-derived addresses, mirroring the `seeds` in the program's account constraints.
+
+use anchor_lang::prelude::Pubkey;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &forward::ID).0
+}
+
+/// `[b"global_config"]`
+pub fn global_config() -> Pubkey {
+    find(&[b"global_config"])
+}
+
+/// `[b"market", polymarket_market_id]`
+pub fn market(polymarket_market_id: &str) -> Pubkey {
+    find(&[b"market", polymarket_market_id.as_bytes()])
+}
+
+/// `[b"pool_state", market]`
+pub fn pool_state(market: &Pubkey) -> Pubkey {
+    find(&[b"pool_state", market.as_ref()])
+}
+
+/// `[b"price_oracle", market]`
+pub fn price_oracle(market: &Pubkey) -> Pubkey {
+    find(&[b"price_oracle", market.as_ref()])
+}
+
+/// `[b"resolution_oracle", market]`
+pub fn resolution_oracle(market: &Pubkey) -> Pubkey {
+    find(&[b"resolution_oracle", market.as_ref()])
+}
+
+/// `[b"collateral_vault", market]`
+pub fn collateral_vault(market: &Pubkey) -> Pubkey {
+    find(&[b"collateral_vault", market.as_ref()])
+}
+
+/// `[b"fee_vault"]`
+pub fn fee_vault() -> Pubkey {
+    find(&[b"fee_vault"])
+}
+
+/// `[b"fee_ledger"]`
+pub fn fee_ledger() -> Pubkey {
+    find(&[b"fee_ledger"])
+}
+
+/// `[b"referrer", authority]`
+pub fn referrer(authority: &Pubkey) -> Pubkey {
+    find(&[b"referrer", authority.as_ref()])
+}
+
+/// `[b"lp_mint", market]`
+pub fn lp_mint(market: &Pubkey) -> Pubkey {
+    find(&[b"lp_mint", market.as_ref()])
+}
+
+/// `[b"bond_vault", market]`
+pub fn bond_vault(market: &Pubkey) -> Pubkey {
+    find(&[b"bond_vault", market.as_ref()])
+}
+
+/// `[b"position", market, position_id]`, where `position_id` is the pool's
+/// `position_counter` when the position was opened
+pub fn position(market: &Pubkey, position_id: u64) -> Pubkey {
+    find(&[b"position", market.as_ref(), &position_id.to_le_bytes()])
+}
+
+/// `[b"multisig"]`
+pub fn multisig() -> Pubkey {
+    find(&[b"multisig"])
+}
+
+/// `[b"multisig_signer", multisig]`
+pub fn multisig_signer(multisig: &Pubkey) -> Pubkey {
+    find(&[b"multisig_signer", multisig.as_ref()])
+}
+
+/// `[b"proposal", multisig, index]`, where `index` is the multisig's `proposal_count`
+/// when the proposal was created
+pub fn proposal(multisig: &Pubkey, index: u64) -> Pubkey {
+    find(&[b"proposal", multisig.as_ref(), &index.to_le_bytes()])
+}
+
+/// `[b"__event_authority"]`, the signer Anchor's `emit_cpi!` uses for its self-CPI
+pub fn event_authority() -> Pubkey {
+    find(&[b"__event_authority"])
+}
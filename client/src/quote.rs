@@ -0,0 +1,439 @@
+//! Off-chain trade previews.
+//!
+//! These call the same `forward::math::quote_open` / `quote_close` functions as
+//! the program, with the curve parameters and oracle checks resolved exactly as
+//! a trade at `now` would, so the numbers match the on-chain `quote_open` and
+//! `quote_close` instructions for the same account state.
+
+use anchor_lang::prelude::*;
+use forward::errors::ForwardError;
+use forward::math;
+use forward::oracle::{self, PriceOracle};
+use forward::state::{CloseQuote, Direction, GlobalConfig, MarketConfig, MarketStatus, PoolState, Position, PositionStatus, Quote};
+
+/// Preview opening (or increasing by) `size` in `direction`
+///
+/// `vault_amount` is the market collateral vault's balance, which bounds
+/// `max_size` together with the free LP liquidity.
+#[allow(clippy::too_many_arguments)]
+pub fn quote_open(
+    global_config: &GlobalConfig,
+    market_config: &MarketConfig,
+    pool_state: &PoolState,
+    price_oracle: &PriceOracle,
+    vault_amount: u64,
+    direction: Direction,
+    size: u64,
+    now: i64,
+) -> Result<Quote> {
+    require!(
+        market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+    require!(size > 0, ForwardError::PositionSizeExceedsLimit);
+
+    let curve_params = market_config.active_curve_params(global_config, now);
+    let polymarket_price = oracle::validate_price(price_oracle, now)?;
+    require!(polymarket_price > 0, ForwardError::InvalidOracleData);
+
+    let mut quote = math::quote_open(
+        polymarket_price,
+        pool_state,
+        &curve_params,
+        &market_config.risk_limits,
+        market_config.effective_fee_bps(global_config),
+        direction,
+        size,
+    )?;
+    quote.max_size = math::calculate_fillable_size(
+        polymarket_price,
+        pool_state,
+        &curve_params,
+        &market_config.risk_limits,
+        direction,
+        vault_amount,
+        quote.max_size,
+    );
+    Ok(quote)
+}
+
+/// Preview closing `position`, or reducing it by `size`
+pub fn quote_close(
+    global_config: &GlobalConfig,
+    market_config: &MarketConfig,
+    pool_state: &PoolState,
+    price_oracle: &PriceOracle,
+    position: &Position,
+    size: Option<u64>,
+    now: i64,
+) -> Result<CloseQuote> {
+    require!(
+        market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+    require!(
+        position.status == PositionStatus::Open,
+        ForwardError::PositionAlreadySettled
+    );
+
+    let curve_params = market_config.active_curve_params(global_config, now);
+    let polymarket_price = oracle::validate_price(price_oracle, now)?;
+    require!(polymarket_price > 0, ForwardError::InvalidOracleData);
+
+    math::quote_close(
+        polymarket_price,
+        pool_state,
+        &curve_params,
+        position,
+        market_config.effective_fee_bps(global_config),
+        size.unwrap_or(position.size),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sysvar::program_stubs::{self, SyscallStubs};
+    use forward::instructions::quote_close::{QuoteClose, QuoteCloseBumps};
+    use forward::instructions::quote_open::{QuoteOpen, QuoteOpenBumps};
+    use forward::test_utils::StoredAccount;
+    use forward::state::{
+        CurveBreakpoint, CurveKind, CurveParams, PendingCurveParams,
+        PendingMarketCurveParams, ResolutionParams, RiskLimits,
+    };
+    use std::sync::Once;
+
+    const NOW: i64 = 1_700_000_000;
+
+    /// Serve a fixed `Clock` to the on-chain handlers
+    struct FixedClock;
+
+    impl SyscallStubs for FixedClock {
+        fn sol_log(&self, _message: &str) {}
+
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: NOW,
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            0
+        }
+    }
+
+    fn install_clock() {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(FixedClock));
+        });
+    }
+
+    fn curve(kind: CurveKind, alpha: u64) -> CurveParams {
+        let mut breakpoints = [CurveBreakpoint::default(); CurveParams::MAX_BREAKPOINTS];
+        breakpoints[0] = CurveBreakpoint { exposure: 5000, value: 2000 };
+        breakpoints[1] = CurveBreakpoint { exposure: 10000, value: 10000 };
+        let curve = CurveParams {
+            alpha,
+            beta: 200,
+            max_exposure: 10_000_000,
+            min_price: 100,
+            max_price: 9900,
+            kind,
+            steepness: 30_000,
+            breakpoint_count: 2,
+            breakpoints,
+        };
+        assert!(curve.validate().is_ok());
+        curve
+    }
+
+    fn global_config() -> GlobalConfig {
+        GlobalConfig {
+            admin: Pubkey::new_unique(),
+            pending_admin: None,
+            price_keeper: Pubkey::new_unique(),
+            resolution_keeper: Pubkey::new_unique(),
+            risk_manager: Pubkey::new_unique(),
+            collateral_mint: Pubkey::new_unique(),
+            curve_params: curve(CurveKind::Linear, 1000),
+            pending_curve_params: Some(PendingCurveParams {
+                curve_params: curve(CurveKind::Tanh, 1500),
+                activates_at: NOW - 10,
+            }),
+            curve_update_delay: 3600,
+            pending_curve_update_delay: None,
+            resolution_params: ResolutionParams {
+                bond_amount: 0,
+                liveness_period: 3600,
+            },
+            fee_bps: 30,
+            referral_tiers: [0; GlobalConfig::MAX_REFERRAL_TIERS],
+            crank_fee: 0,
+            bump: 255,
+        }
+    }
+
+    fn market_config(
+        curve_params: Option<CurveParams>,
+        pending_curve_params: Option<PendingMarketCurveParams>,
+        fee_bps: Option<u64>,
+    ) -> MarketConfig {
+        MarketConfig {
+            polymarket_market_id: "parity".to_string(),
+            resolution_timestamp: NOW + 86_400,
+            price_oracle: Pubkey::new_unique(),
+            resolution_oracle: Pubkey::new_unique(),
+            risk_limits: RiskLimits {
+                max_total_exposure: 50_000_000,
+                max_long_share: 8000,
+                max_short_share: 8000,
+            },
+            status: MarketStatus::Active,
+            pool_state: Pubkey::new_unique(),
+            collateral_vault: Pubkey::new_unique(),
+            lp_mint: Pubkey::new_unique(),
+            curve_params,
+            pending_curve_params,
+            fee_bps,
+            bump: 255,
+        }
+    }
+
+    /// Global curve with an elapsed queued change, a market override with a
+    /// queued removal, and a market override whose queued change is still locked
+    fn markets() -> Vec<MarketConfig> {
+        vec![
+            market_config(None, None, None),
+            market_config(
+                Some(curve(CurveKind::Exponential, 800)),
+                Some(PendingMarketCurveParams {
+                    curve_params: None,
+                    activates_at: NOW,
+                }),
+                Some(50),
+            ),
+            market_config(
+                Some(curve(CurveKind::Piecewise, 1200)),
+                Some(PendingMarketCurveParams {
+                    curve_params: Some(curve(CurveKind::Linear, 3000)),
+                    activates_at: NOW + 1,
+                }),
+                Some(0),
+            ),
+        ]
+    }
+
+    fn pool_state(market: Pubkey) -> PoolState {
+        PoolState {
+            market,
+            total_long_exposure: 4_000_000,
+            total_short_exposure: 1_500_000,
+            pool_collateral: 2_000_000,
+            position_counter: 3,
+            lp_nav: 20_000_000,
+            bump: 255,
+        }
+    }
+
+    fn price_oracle(price: u64) -> PriceOracle {
+        PriceOracle {
+            price,
+            timestamp: NOW - 5,
+            exponent: -4,
+        }
+    }
+
+    fn position(market: Pubkey, direction: Direction) -> Position {
+        Position {
+            owner: Pubkey::new_unique(),
+            market,
+            direction,
+            size: 1_000_000,
+            forward_price: 4200,
+            collateral_locked: 420_000,
+            premium_paid: 1_234,
+            fee_paid: 1_260,
+            status: PositionStatus::Open,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn quote_open_matches_on_chain_handler() {
+        install_clock();
+        let global_config = global_config();
+        for market_config in markets() {
+            for price in [150, 5000, 9700, 10_001] {
+                for direction in [Direction::Long, Direction::Short] {
+                    for (size, vault_amount) in [(1, 30_000_000), (250_000, 4_100_000), (3_000_000, 30_000_000)] {
+                        let pool_state = pool_state(Pubkey::new_unique());
+                        let price_oracle = price_oracle(price);
+                        let off_chain = quote_open(
+                            &global_config,
+                            &market_config,
+                            &pool_state,
+                            &price_oracle,
+                            vault_amount,
+                            direction,
+                            size,
+                            NOW,
+                        );
+
+                        let mut stored = [
+                            StoredAccount::new(&global_config),
+                            StoredAccount::new(&market_config),
+                            StoredAccount::new(&pool_state),
+                            StoredAccount::token_account(
+                                Pubkey::new_unique(),
+                                Pubkey::new_unique(),
+                                vault_amount,
+                            ),
+                            StoredAccount::new(&price_oracle),
+                        ];
+                        let [g, m, p, v, o] = &mut stored;
+                        let (g, m, p, v, o) =
+                            (g.info(false), m.info(false), p.info(false), v.info(false), o.info(false));
+                        let mut accounts = QuoteOpen {
+                            global_config: Account::try_from(&g).unwrap(),
+                            market_config: Account::try_from(&m).unwrap(),
+                            pool_state: Account::try_from(&p).unwrap(),
+                            collateral_vault: Account::try_from(&v).unwrap(),
+                            price_oracle: Account::try_from(&o).unwrap(),
+                        };
+                        let ctx = Context::new(
+                            &forward::ID,
+                            &mut accounts,
+                            &[],
+                            QuoteOpenBumps::default(),
+                        );
+                        let on_chain =
+                            forward::instructions::quote_open::handler(ctx, direction, size);
+
+                        assert_eq!(
+                            format!("{off_chain:?}"),
+                            format!("{on_chain:?}"),
+                            "price {price}, {direction:?}, size {size}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn quote_open_max_size_fits_liquidity_and_solvency() {
+        let global_config = global_config();
+        let mut market_config = markets().remove(0);
+        let mut pool_state = pool_state(Pubkey::new_unique());
+        let price_oracle = price_oracle(5000);
+        let quote = |market_config: &MarketConfig, pool_state: &PoolState, vault_amount: u64| {
+            quote_open(
+                &global_config,
+                market_config,
+                pool_state,
+                &price_oracle,
+                vault_amount,
+                Direction::Long,
+                1,
+                NOW,
+            )
+        };
+
+        let risk_max = quote(&market_config, &pool_state, u64::MAX / 2).unwrap().max_size;
+        assert!(risk_max > 0);
+
+        // Little free LP capital caps the size below the risk limits, and the
+        // capped size passes the same pool checks open_position runs
+        pool_state.lp_nav = pool_state.pool_collateral + 100_000;
+        let max_size = quote(&market_config, &pool_state, u64::MAX / 2).unwrap().max_size;
+        assert!(max_size > 0 && max_size < risk_max);
+        let fill = quote_open(
+            &global_config,
+            &market_config,
+            &pool_state,
+            &price_oracle,
+            u64::MAX / 2,
+            Direction::Long,
+            max_size,
+            NOW,
+        )
+        .unwrap();
+        let mut pool_after = pool_state.clone();
+        pool_after.open(Direction::Long, max_size, fill.pool_collateral, fill.premium).unwrap();
+
+        // A vault that can't cover the existing exposure leaves no room at all
+        assert_eq!(quote(&market_config, &pool_state, 1_000_000).unwrap().max_size, 0);
+
+        market_config.status = MarketStatus::TradingClosed;
+        assert_eq!(
+            quote(&market_config, &pool_state, u64::MAX / 2).unwrap_err(),
+            ForwardError::MarketNotActive.into()
+        );
+    }
+
+    #[test]
+    fn quote_close_matches_on_chain_handler() {
+        install_clock();
+        let global_config = global_config();
+        let mut trading_closed = markets().remove(0);
+        trading_closed.status = MarketStatus::TradingClosed;
+        for market_config in markets().into_iter().chain([trading_closed]) {
+            for price in [150, 5000, 9700, 10_001] {
+                for direction in [Direction::Long, Direction::Short] {
+                    for size in [None, Some(1), Some(400_000)] {
+                        let pool_state = pool_state(Pubkey::new_unique());
+                        let price_oracle = price_oracle(price);
+                        let position = position(pool_state.market, direction);
+                        let off_chain = quote_close(
+                            &global_config,
+                            &market_config,
+                            &pool_state,
+                            &price_oracle,
+                            &position,
+                            size,
+                            NOW,
+                        );
+
+                        let mut stored = [
+                            StoredAccount::new(&global_config),
+                            StoredAccount::new(&market_config),
+                            StoredAccount::new(&pool_state),
+                            StoredAccount::new(&price_oracle),
+                            StoredAccount::new(&position),
+                        ];
+                        let [g, m, p, o, pos] = &mut stored;
+                        let (g, m, p, o, pos) =
+                            (g.info(false), m.info(false), p.info(false), o.info(false), pos.info(false));
+                        let mut accounts = QuoteClose {
+                            global_config: Account::try_from(&g).unwrap(),
+                            market_config: Account::try_from(&m).unwrap(),
+                            pool_state: Account::try_from(&p).unwrap(),
+                            price_oracle: Account::try_from(&o).unwrap(),
+                            position: Account::try_from(&pos).unwrap(),
+                        };
+                        let ctx = Context::new(
+                            &forward::ID,
+                            &mut accounts,
+                            &[],
+                            QuoteCloseBumps::default(),
+                        );
+                        let on_chain = forward::instructions::quote_close::handler(ctx, size);
+
+                        // Errors raised before the shared math carry each caller's source location
+                        if market_config.status != MarketStatus::Active {
+                            assert_eq!(off_chain.unwrap_err(), ForwardError::MarketNotActive.into());
+                            assert_eq!(on_chain.unwrap_err(), ForwardError::MarketNotActive.into());
+                            continue;
+                        }
+
+                        assert_eq!(
+                            format!("{off_chain:?}"),
+                            format!("{on_chain:?}"),
+                            "price {price}, {direction:?}, size {size:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
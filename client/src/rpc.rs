@@ -0,0 +1,135 @@
+//! Minimal blocking Solana JSON-RPC client: just the calls the CLI and
+//! keeper need. Enabled by the `rpc` feature.
+
+use std::error::Error;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_transaction::Transaction;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+pub struct RpcClient {
+    url: String,
+}
+
+impl RpcClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let response: Value = ureq::post(&self.url)
+            .send_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))?
+            .into_json()?;
+
+        if let Some(error) = response.get("error") {
+            let mut message = format!("{} failed: {}", method, error["message"]);
+            // Preflight failures carry the program logs
+            if let Some(logs) = error["data"]["logs"].as_array() {
+                for line in logs {
+                    message.push_str(&format!("\n  {}", line.as_str().unwrap_or_default()));
+                }
+            }
+            return Err(message.into());
+        }
+        Ok(response["result"].clone())
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or("getLatestBlockhash: missing blockhash")?;
+        Ok(Hash::from_str(blockhash)?)
+    }
+
+    /// Account data, or `None` if the account does not exist
+    pub fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        if result["value"].is_null() {
+            return Ok(None);
+        }
+        Ok(Some(decode_data(&result["value"]["data"])?))
+    }
+
+    /// Account data, failing with `what` in the message if the account does not exist
+    pub fn get_required_account_data(&self, address: &Pubkey, what: &str) -> Result<Vec<u8>> {
+        self.get_account_data(address)?
+            .ok_or_else(|| format!("{} account {} not found", what, address).into())
+    }
+
+    /// Accounts owned by `program` whose data starts with `prefix`
+    pub fn get_program_accounts(&self, program: &Pubkey, prefix: &[u8]) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let result = self.call(
+            "getProgramAccounts",
+            json!([
+                program.to_string(),
+                {
+                    "encoding": "base64",
+                    "commitment": "confirmed",
+                    "filters": [{ "memcmp": { "offset": 0, "bytes": BASE64.encode(prefix), "encoding": "base64" } }],
+                },
+            ]),
+        )?;
+        result
+            .as_array()
+            .ok_or("getProgramAccounts: expected an array")?
+            .iter()
+            .map(|entry| {
+                let address = Pubkey::from_str(entry["pubkey"].as_str().unwrap_or_default())?;
+                Ok((address, decode_data(&entry["account"]["data"])?))
+            })
+            .collect()
+    }
+
+    /// Send a signed transaction and wait until it is confirmed
+    pub fn send_and_confirm(&self, transaction: &Transaction) -> Result<String> {
+        let signature = self
+            .call(
+                "sendTransaction",
+                json!([
+                    BASE64.encode(bincode::serialize(transaction)?),
+                    { "encoding": "base64", "preflightCommitment": "confirmed" },
+                ]),
+            )?
+            .as_str()
+            .ok_or("sendTransaction: missing signature")?
+            .to_string();
+
+        let started = Instant::now();
+        while started.elapsed() < Duration::from_secs(60) {
+            let result = self.call("getSignatureStatuses", json!([[signature]]))?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    return Err(format!("transaction {} failed: {}", signature, status["err"]).into());
+                }
+                if matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized")) {
+                    return Ok(signature);
+                }
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+        Err(format!("transaction {} was not confirmed within 60s", signature).into())
+    }
+}
+
+fn decode_data(data: &Value) -> Result<Vec<u8>> {
+    let encoded = data[0].as_str().ok_or("account data is not base64-encoded")?;
+    Ok(BASE64.decode(encoded)?)
+}
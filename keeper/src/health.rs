@@ -0,0 +1,182 @@
+//! Keeper health, served as JSON on `GET /health`.
+//!
+//! The keeper is healthy while its last tick succeeded within two intervals
+//! and every active market has had a price pushed within `MAX_PRICE_AGE`;
+//! otherwise `/health` answers 503 so a supervisor can alert or restart it.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use forward_client::MAX_PRICE_AGE;
+use serde_json::{json, Value};
+
+#[derive(Clone, Default)]
+pub struct Health {
+    state: Arc<Mutex<HealthState>>,
+}
+
+#[derive(Default)]
+struct HealthState {
+    last_tick: Option<i64>,
+    last_tick_error: Option<String>,
+    markets: BTreeMap<String, MarketHealth>,
+}
+
+#[derive(Default)]
+struct MarketHealth {
+    status: String,
+    active: bool,
+    last_price: Option<u64>,
+    last_pushed_at: Option<i64>,
+    last_error: Option<String>,
+}
+
+impl Health {
+    pub fn tick_finished(&self, now: i64, error: Option<String>) {
+        let mut state = self.state.lock().unwrap();
+        if error.is_none() {
+            state.last_tick = Some(now);
+        }
+        state.last_tick_error = error;
+    }
+
+    /// Record a market's on-chain status as seen this tick
+    pub fn market_seen(&self, market_id: &str, status: String, active: bool) {
+        let mut state = self.state.lock().unwrap();
+        let market = state.markets.entry(market_id.to_string()).or_default();
+        market.status = status;
+        market.active = active;
+    }
+
+    pub fn price_pushed(&self, market_id: &str, price: u64, now: i64) {
+        let mut state = self.state.lock().unwrap();
+        let market = state.markets.entry(market_id.to_string()).or_default();
+        market.last_price = Some(price);
+        market.last_pushed_at = Some(now);
+        market.last_error = None;
+    }
+
+    pub fn market_failed(&self, market_id: &str, error: String) {
+        let mut state = self.state.lock().unwrap();
+        state.markets.entry(market_id.to_string()).or_default().last_error = Some(error);
+    }
+
+    /// HTTP status and JSON body for `/health`
+    pub fn report(&self, now: i64, interval: i64) -> (u16, Value) {
+        let state = self.state.lock().unwrap();
+
+        let tick_fresh = state
+            .last_tick
+            .is_some_and(|last_tick| now.saturating_sub(last_tick) <= interval.saturating_mul(2));
+        let mut stale_markets = Vec::new();
+        let mut markets = serde_json::Map::new();
+        for (market_id, market) in &state.markets {
+            let price_fresh = market
+                .last_pushed_at
+                .is_some_and(|pushed_at| now.saturating_sub(pushed_at) <= MAX_PRICE_AGE);
+            if market.active && !price_fresh {
+                stale_markets.push(market_id.clone());
+            }
+            markets.insert(
+                market_id.clone(),
+                json!({
+                    "status": market.status,
+                    "last_price": market.last_price,
+                    "last_pushed_at": market.last_pushed_at,
+                    "last_error": market.last_error,
+                }),
+            );
+        }
+
+        let healthy = tick_fresh && state.last_tick_error.is_none() && stale_markets.is_empty();
+        let body = json!({
+            "status": if healthy { "ok" } else { "unhealthy" },
+            "now": now,
+            "last_tick": state.last_tick,
+            "last_tick_error": state.last_tick_error,
+            "stale_markets": stale_markets,
+            "markets": markets,
+        });
+        (if healthy { 200 } else { 503 }, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const INTERVAL: i64 = 30;
+
+    fn healthy() -> Health {
+        let health = Health::default();
+        health.market_seen("a", "Active".to_string(), true);
+        health.price_pushed("a", 6200, NOW - 10);
+        health.tick_finished(NOW - 10, None);
+        health
+    }
+
+    #[test]
+    fn fresh_tick_and_prices_are_healthy() {
+        let (status, body) = healthy().report(NOW, INTERVAL);
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "ok");
+        assert_eq!(body["markets"]["a"]["last_price"], 6200);
+    }
+
+    #[test]
+    fn no_tick_yet_is_unhealthy() {
+        let (status, _) = Health::default().report(NOW, INTERVAL);
+        assert_eq!(status, 503);
+    }
+
+    #[test]
+    fn stale_tick_is_unhealthy() {
+        let health = healthy();
+        health.price_pushed("a", 6200, NOW);
+        let (status, body) = health.report(NOW - 10 + 2 * INTERVAL + 1, INTERVAL);
+        assert_eq!(status, 503);
+        assert_eq!(body["status"], "unhealthy");
+    }
+
+    #[test]
+    fn failed_tick_is_unhealthy() {
+        let health = healthy();
+        health.tick_finished(NOW, Some("rpc down".to_string()));
+        let (status, body) = health.report(NOW, INTERVAL);
+        assert_eq!(status, 503);
+        assert_eq!(body["last_tick_error"], "rpc down");
+        assert_eq!(body["last_tick"], NOW - 10);
+    }
+
+    #[test]
+    fn stale_market_is_unhealthy() {
+        let health = healthy();
+        health.tick_finished(NOW + MAX_PRICE_AGE, None);
+        let (status, body) = health.report(NOW + MAX_PRICE_AGE, INTERVAL);
+        assert_eq!(status, 503);
+        assert_eq!(body["stale_markets"], json!(["a"]));
+    }
+
+    #[test]
+    fn market_without_a_price_is_unhealthy() {
+        let health = healthy();
+        health.market_seen("b", "Active".to_string(), true);
+        health.market_failed("b", "no price".to_string());
+        let (status, body) = health.report(NOW, INTERVAL);
+        assert_eq!(status, 503);
+        assert_eq!(body["stale_markets"], json!(["b"]));
+        assert_eq!(body["markets"]["b"]["last_error"], "no price");
+    }
+
+    #[test]
+    fn market_closed_for_trading_is_not_stale() {
+        let health = healthy();
+        health.market_seen("b", "TradingClosed".to_string(), false);
+        health.price_pushed("b", 5000, NOW - 2 * MAX_PRICE_AGE);
+        let (status, body) = health.report(NOW, INTERVAL);
+        assert_eq!(status, 200);
+        assert_eq!(body["stale_markets"], json!([]));
+        assert_eq!(body["markets"]["b"]["status"], "TradingClosed");
+    }
+}
@@ -0,0 +1,73 @@
+//! Minimal blocking HTTP server for the health endpoint and the stub price
+//! server. Handles `GET` only and always answers with JSON.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use forward_client::rpc::Result;
+use serde_json::{json, Value};
+
+/// Connections are served one at a time, so a client that stalls is dropped
+/// after this long rather than blocking `/health` for everyone else
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serve `handler(path) -> (status, body)` on `addr` from a background thread
+pub fn serve<F>(addr: &str, handler: F) -> Result<JoinHandle<()>>
+where
+    F: Fn(&str) -> (u16, Value) + Send + 'static,
+{
+    let listener = TcpListener::bind(addr).map_err(|err| format!("failed to bind {}: {}", addr, err))?;
+    Ok(thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            if let Err(err) = respond(stream, &handler) {
+                eprintln!("http: {}", err);
+            }
+        }
+    }))
+}
+
+fn respond<F>(mut stream: TcpStream, handler: &F) -> io::Result<()>
+where
+    F: Fn(&str) -> (u16, Value),
+{
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers so closing the socket doesn't reset the connection
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(path)) => handler(path),
+        _ => (405, json!({ "error": "method not allowed" })),
+    };
+
+    let body = body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason(status),
+        body.len(),
+        body
+    )
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
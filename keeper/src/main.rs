@@ -0,0 +1,260 @@
+//! `forward-keeper`: keeps every market's price oracle fresh.
+//!
+//! Each tick the keeper lists all `MarketConfig` accounts and, for every
+//! active market, either pushes `update_price_oracle` with the latest price
+//! from the configured source or, once `resolution_timestamp` has passed,
+//! calls `close_market_for_trading`. `open_position` rejects prices older
+//! than `MAX_PRICE_AGE`, so the tick interval must be shorter than that.
+
+mod health;
+mod http;
+mod source;
+
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::Discriminator;
+use clap::{Args, Parser, Subcommand};
+use forward_client::rpc::{Result, RpcClient};
+use forward_client::{accounts, instructions, MarketConfig, MarketStatus, BASIS_POINTS, MAX_PRICE_AGE};
+use serde_json::json;
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use health::Health;
+use source::{FileSource, HttpSource, PriceSource};
+
+#[derive(Parser)]
+#[command(name = "forward-keeper", version, about = "Push oracle prices and close markets at resolution time")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the keeper loop
+    Run(RunArgs),
+
+    /// Serve prices from a JSON-lines file over HTTP, for use with --price-url
+    StubServer {
+        /// JSON-lines price file (re-read on every request)
+        #[arg(long)]
+        prices_file: PathBuf,
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        listen: String,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// RPC endpoint (a local validator by default)
+    #[arg(long, env = "FORWARD_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Price keeper keypair file [default: ~/.config/solana/id.json]
+    #[arg(long, short = 'k', env = "FORWARD_KEYPAIR")]
+    keypair: Option<PathBuf>,
+
+    /// Risk manager keypair file for closing markets [default: --keypair]
+    #[arg(long, env = "FORWARD_RISK_MANAGER_KEYPAIR")]
+    risk_manager_keypair: Option<PathBuf>,
+
+    /// Read prices from a JSON-lines file
+    #[arg(long, required_unless_present = "price_url", conflicts_with = "price_url")]
+    prices_file: Option<PathBuf>,
+
+    /// Read prices from an HTTP endpoint (GET <url>/price/<market_id>)
+    #[arg(long)]
+    price_url: Option<String>,
+
+    /// Seconds between ticks
+    #[arg(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..MAX_PRICE_AGE as u64))]
+    interval: u64,
+
+    /// Address for the health endpoint (GET /health)
+    #[arg(long, default_value = "127.0.0.1:9090")]
+    health_listen: String,
+
+    /// Run a single tick and exit
+    #[arg(long)]
+    once: bool,
+}
+
+struct Keeper {
+    rpc: RpcClient,
+    price_keeper: Keypair,
+    risk_manager: Option<Keypair>,
+    health: Health,
+}
+
+impl Keeper {
+    fn risk_manager(&self) -> &Keypair {
+        self.risk_manager.as_ref().unwrap_or(&self.price_keeper)
+    }
+
+    fn send(&self, instruction: Instruction, signer: &Keypair) -> Result<String> {
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.rpc.get_latest_blockhash()?,
+        );
+        self.rpc.send_and_confirm(&transaction)
+    }
+
+    fn tick(&self, source: &mut dyn PriceSource) -> Result<()> {
+        source.refresh()?;
+        let now = unix_now();
+
+        let markets = self
+            .rpc
+            .get_program_accounts(&forward_client::PROGRAM_ID, MarketConfig::DISCRIMINATOR)?;
+        for (address, data) in markets {
+            let market = match accounts::market_config(&data) {
+                Ok(market) => market,
+                Err(err) => {
+                    eprintln!("{}: failed to decode market: {}", address, err);
+                    continue;
+                }
+            };
+            let market_id = market.polymarket_market_id.clone();
+            let active = market.status == MarketStatus::Active;
+            self.health
+                .market_seen(&market_id, format!("{:?}", market.status), active);
+            if !active {
+                continue;
+            }
+
+            // One market failing must not stop the others from being kept fresh
+            if let Err(err) = self.keep_market(source, address, &market, now) {
+                eprintln!("{}: {}", market_id, err);
+                self.health.market_failed(&market_id, err.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn keep_market(
+        &self,
+        source: &dyn PriceSource,
+        address: Pubkey,
+        market: &MarketConfig,
+        now: i64,
+    ) -> Result<()> {
+        let market_id = &market.polymarket_market_id;
+
+        if now >= market.resolution_timestamp {
+            let signature = self.send(
+                instructions::close_market_for_trading(self.risk_manager().pubkey(), address),
+                self.risk_manager(),
+            )?;
+            println!("{}: closed for trading ({})", market_id, signature);
+            self.health
+                .market_seen(market_id, format!("{:?}", MarketStatus::TradingClosed), false);
+            return Ok(());
+        }
+
+        let price = source
+            .price(market_id)?
+            .ok_or("no price from source")?;
+        if price.price > BASIS_POINTS {
+            return Err(format!("source price {} exceeds {} bps", price.price, BASIS_POINTS).into());
+        }
+        let signature = self.send(
+            instructions::update_price_oracle(self.price_keeper.pubkey(), address, price.price, price.exponent),
+            &self.price_keeper,
+        )?;
+        println!("{}: pushed {} bps ({})", market_id, price.price, signature);
+        self.health.price_pushed(market_id, price.price, now);
+        Ok(())
+    }
+}
+
+fn main() {
+    let result = match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::StubServer { prices_file, listen } => stub_server(prices_file, &listen),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let keeper = Keeper {
+        rpc: RpcClient::new(args.url),
+        price_keeper: load_keypair(args.keypair)?,
+        risk_manager: args.risk_manager_keypair.map(|path| load_keypair(Some(path))).transpose()?,
+        health: Health::default(),
+    };
+    let mut source: Box<dyn PriceSource> = match (args.prices_file, args.price_url) {
+        (Some(path), _) => Box::new(FileSource::new(path)),
+        (None, Some(url)) => Box::new(HttpSource::new(url)),
+        (None, None) => return Err("pass --prices-file or --price-url".into()),
+    };
+
+    let interval = args.interval as i64;
+    if !args.once {
+        let health = keeper.health.clone();
+        http::serve(&args.health_listen, move |path| match path {
+            "/health" => health.report(unix_now(), interval),
+            _ => (404, json!({ "error": "not found" })),
+        })?;
+        println!("Health endpoint: http://{}/health", args.health_listen);
+    }
+
+    loop {
+        let result = keeper.tick(source.as_mut());
+        if let Err(err) = &result {
+            eprintln!("tick failed: {}", err);
+        }
+        keeper
+            .health
+            .tick_finished(unix_now(), result.as_ref().err().map(|err| err.to_string()));
+        if args.once {
+            return result;
+        }
+        thread::sleep(Duration::from_secs(args.interval));
+    }
+}
+
+fn stub_server(prices_file: PathBuf, listen: &str) -> Result<()> {
+    println!("Serving {} on http://{}/price/<market_id>", prices_file.display(), listen);
+    let server = http::serve(listen, move |path| {
+        let Some(market_id) = path.strip_prefix("/price/") else {
+            return (404, json!({ "error": "not found" }));
+        };
+        let prices = std::fs::read_to_string(&prices_file)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| source::parse_price_lines(&contents).map_err(|err| err.to_string()));
+        match prices {
+            Ok(prices) => match prices.get(market_id) {
+                Some(price) => (200, json!({ "price": price.price, "exponent": price.exponent })),
+                None => (404, json!({ "error": "no price for market" })),
+            },
+            Err(err) => (500, json!({ "error": err })),
+        }
+    })?;
+    server.join().map_err(|_| "stub server thread panicked")?;
+    Ok(())
+}
+
+fn load_keypair(path: Option<PathBuf>) -> Result<Keypair> {
+    let path = match path {
+        Some(path) => path,
+        None => PathBuf::from(std::env::var("HOME")?).join(".config/solana/id.json"),
+    };
+    read_keypair_file(&path).map_err(|err| format!("failed to read keypair {}: {}", path.display(), err).into())
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
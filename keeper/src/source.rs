@@ -0,0 +1,182 @@
+//! Price sources the keeper reads from.
+//!
+//! Prices are Polymarket YES prices in basis points, keyed by Polymarket
+//! market ID. A source returns `None` for markets it has no price for; the
+//! keeper then skips the push and reports the market as unhealthy.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use forward_client::rpc::Result;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SourcePrice {
+    pub price: u64,
+    pub exponent: i8,
+}
+
+pub trait PriceSource {
+    /// Called once per keeper tick before any `price` lookups
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn price(&self, market_id: &str) -> Result<Option<SourcePrice>>;
+}
+
+/// Prices from a JSON-lines file, re-read on every tick
+///
+/// Each line is `{"market_id": "...", "price": 6200, "exponent": 0}`
+/// (`exponent` is optional); later lines override earlier ones, so a feed can
+/// simply append.
+pub struct FileSource {
+    path: PathBuf,
+    prices: HashMap<String, SourcePrice>,
+}
+
+impl FileSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            prices: HashMap::new(),
+        }
+    }
+}
+
+impl PriceSource for FileSource {
+    fn refresh(&mut self) -> Result<()> {
+        let contents = fs::read_to_string(&self.path)
+            .map_err(|err| format!("failed to read {}: {}", self.path.display(), err))?;
+        self.prices = parse_price_lines(&contents)?;
+        Ok(())
+    }
+
+    fn price(&self, market_id: &str) -> Result<Option<SourcePrice>> {
+        Ok(self.prices.get(market_id).copied())
+    }
+}
+
+/// Prices from an HTTP endpoint: `GET {base_url}/price/{market_id}`
+///
+/// The response body is `{"price": 6200, "exponent": 0}`; a 404 means no price.
+/// `forward-keeper stub-server` serves this API from a JSON-lines file.
+pub struct HttpSource {
+    base_url: String,
+}
+
+impl HttpSource {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl PriceSource for HttpSource {
+    fn price(&self, market_id: &str) -> Result<Option<SourcePrice>> {
+        let url = format!("{}/price/{}", self.base_url, market_id);
+        match ureq::get(&url).call() {
+            Ok(response) => Ok(Some(parse_price(&response.into_json()?)?)),
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(err) => Err(format!("{}: {}", url, err).into()),
+        }
+    }
+}
+
+/// Parse `{"price": ..., "exponent": ...}`
+pub fn parse_price(value: &Value) -> Result<SourcePrice> {
+    let price = value["price"]
+        .as_u64()
+        .ok_or_else(|| format!("missing or invalid price in {}", value))?;
+    let exponent = match &value["exponent"] {
+        Value::Null => 0,
+        exponent => exponent
+            .as_i64()
+            .and_then(|exponent| i8::try_from(exponent).ok())
+            .ok_or_else(|| format!("invalid exponent in {}", value))?,
+    };
+    Ok(SourcePrice { price, exponent })
+}
+
+/// Parse a JSON-lines price file into the latest price per market
+pub fn parse_price_lines(contents: &str) -> Result<HashMap<String, SourcePrice>> {
+    let mut prices = HashMap::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let value: Value =
+            serde_json::from_str(line).map_err(|err| format!("line {}: {}", index + 1, err))?;
+        let market_id = value["market_id"]
+            .as_str()
+            .ok_or_else(|| format!("line {}: missing market_id", index + 1))?;
+        let price = parse_price(&value).map_err(|err| format!("line {}: {}", index + 1, err))?;
+        prices.insert(market_id.to_string(), price);
+    }
+    Ok(prices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_price_with_optional_exponent() {
+        assert_eq!(
+            parse_price(&json!({"price": 6200})).unwrap(),
+            SourcePrice { price: 6200, exponent: 0 }
+        );
+        assert_eq!(
+            parse_price(&json!({"price": 62, "exponent": -2})).unwrap(),
+            SourcePrice { price: 62, exponent: -2 }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_prices_and_exponents() {
+        assert!(parse_price(&json!({})).is_err());
+        assert!(parse_price(&json!({"price": -1})).is_err());
+        assert!(parse_price(&json!({"price": "6200"})).is_err());
+        assert!(parse_price(&json!({"price": 6200, "exponent": 1.5})).is_err());
+        assert!(parse_price(&json!({"price": 6200, "exponent": "0"})).is_err());
+    }
+
+    #[test]
+    fn exponent_must_fit_an_i8() {
+        assert_eq!(parse_price(&json!({"price": 1, "exponent": 127})).unwrap().exponent, 127);
+        assert_eq!(parse_price(&json!({"price": 1, "exponent": -128})).unwrap().exponent, -128);
+        assert!(parse_price(&json!({"price": 1, "exponent": 128})).is_err());
+        assert!(parse_price(&json!({"price": 1, "exponent": -129})).is_err());
+    }
+
+    #[test]
+    fn later_lines_override_earlier_ones() {
+        let prices = parse_price_lines(
+            r#"{"market_id": "a", "price": 6200}
+
+{"market_id": "b", "price": 31, "exponent": -2}
+{"market_id": "a", "price": 6500}
+"#,
+        )
+        .unwrap();
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices["a"], SourcePrice { price: 6500, exponent: 0 });
+        assert_eq!(prices["b"], SourcePrice { price: 31, exponent: -2 });
+    }
+
+    #[test]
+    fn rejects_bad_lines() {
+        assert!(parse_price_lines("not json").is_err());
+        assert!(parse_price_lines(r#"{"price": 6200}"#).is_err());
+        assert!(parse_price_lines(r#"{"market_id": 7, "price": 6200}"#).is_err());
+        assert!(parse_price_lines(r#"{"market_id": "a"}"#).is_err());
+        assert!(parse_price_lines(r#"{"market_id": "a", "price": 6200, "exponent": 300}"#).is_err());
+
+        let error = parse_price_lines("{\"market_id\": \"a\", \"price\": 1}\n{").unwrap_err();
+        assert!(error.to_string().starts_with("line 2:"));
+    }
+}
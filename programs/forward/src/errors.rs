@@ -26,7 +26,7 @@ pub enum ForwardError {
     #[msg("Invalid oracle data")]
     InvalidOracleData,
     
-    #[msg("Unauthorized: admin only")]
+    #[msg("Unauthorized: missing required role")]
     Unauthorized,
     
     #[msg("Invalid market status")]
@@ -40,5 +40,95 @@ pub enum ForwardError {
     
     #[msg("Invalid mint")]
     InvalidMint,
+    
+    #[msg("Insufficient free pool liquidity")]
+    InsufficientLiquidity,
+    
+    #[msg("Vault balance cannot cover worst-case payout")]
+    VaultInsolvent,
+    
+    #[msg("Market has not been cancelled")]
+    MarketNotCancelled,
+    
+    #[msg("Resolution is not in the expected state")]
+    InvalidResolutionState,
+    
+    #[msg("Market cannot be resolved before its resolution timestamp")]
+    ResolutionTooEarly,
+    
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+    
+    #[msg("Invalid multisig members or threshold")]
+    InvalidMultisigConfig,
+    
+    #[msg("Signer is not a multisig member")]
+    NotMultisigMember,
+    
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+    
+    #[msg("No cancellable curve parameter update is pending")]
+    InvalidCurveParamsUpdate,
+    
+    #[msg("Forward price is beyond the limit price")]
+    LimitPriceExceeded,
+    
+    #[msg("Premium exceeds the maximum premium")]
+    MaxPremiumExceeded,
+    
+    #[msg("Order has expired")]
+    OrderExpired,
+    
+    #[msg("Fee exceeds the maximum fee")]
+    FeeTooHigh,
+    
+    #[msg("Invalid referral tier")]
+    InvalidReferralTier,
+    
+    #[msg("Traders cannot refer themselves")]
+    SelfReferral,
+    
+    #[msg("No referral fees to claim")]
+    NoReferralFeesToClaim,
+    
+    #[msg("Position is still open")]
+    PositionStillOpen,
+    
+    #[msg("Market still has open positions or LP shares")]
+    MarketNotEmpty,
+    
+    #[msg("Crank expects writable position and owner collateral account pairs")]
+    InvalidCrankAccounts,
+    
+    #[msg("Fee ledger account is required with a referrer")]
+    FeeLedgerRequired,
+    
+    #[msg("Bond vault and both bond holders' collateral accounts are required")]
+    BondAccountsRequired,
+    
+    #[msg("Multisig members or threshold changed since the proposal was created")]
+    ProposalStale,
+    
+    #[msg("Proposals cannot execute other proposals")]
+    InvalidProposalInstruction,
+    
+    #[msg("Payout after fees is below the minimum payout")]
+    MinPayoutNotMet,
+    
+    #[msg("Bond vault account is required once an outcome has been proposed")]
+    BondVaultRequired,
+    
+    #[msg("Curve update delay must be between zero and the maximum delay")]
+    InvalidCurveUpdateDelay,
+    
+    #[msg("Resolution bond must be positive and the liveness period within the maximum")]
+    InvalidResolutionParams,
 }
 
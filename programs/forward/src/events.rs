@@ -0,0 +1,425 @@
+use anchor_lang::prelude::*;
+use crate::state::{CurveParams, Direction, PositionStatus, ResolutionParams, RiskLimits, Role};
+
+// Events are emitted with `emit_cpi!`, which records them as self-CPI
+// instruction data rather than program logs, so indexers still see them when
+// a transaction's logs are truncated.
+
+/// Emitted by `init_global_config`
+#[event]
+#[derive(Clone)]
+pub struct GlobalConfigInitialized {
+    pub admin: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub curve_params: CurveParams,
+    pub curve_update_delay: i64,
+    pub resolution_params: ResolutionParams,
+    pub fee_bps: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_market`
+#[event]
+#[derive(Clone)]
+pub struct MarketCreated {
+    pub market: Pubkey,
+    pub polymarket_market_id: String,
+    pub resolution_timestamp: i64,
+    pub risk_limits: RiskLimits,
+    pub curve_params: Option<CurveParams>, // Per-market override, if any
+    pub fee_bps: Option<u64>,              // Per-market override, if any
+    pub timestamp: i64,
+}
+
+/// Emitted when a curve change is queued behind the timelock
+#[event]
+#[derive(Clone)]
+pub struct CurveParamsUpdated {
+    pub market: Option<Pubkey>,            // None for the global curve
+    pub curve_params: Option<CurveParams>, // None removes the market override
+    pub activates_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a queued curve change is withdrawn before it activates
+#[event]
+#[derive(Clone)]
+pub struct CurveParamsUpdateCancelled {
+    pub market: Option<Pubkey>, // None for the global curve
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_curve_update_delay`
+#[event]
+#[derive(Clone)]
+pub struct CurveUpdateDelayUpdated {
+    pub curve_update_delay: i64,
+    pub activates_at: i64, // Later than `timestamp` when a shorter delay is queued
+    pub timestamp: i64,
+}
+
+/// Emitted by `close_market_for_trading`
+#[event]
+#[derive(Clone)]
+pub struct MarketClosed {
+    pub market: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `cancel_market`
+#[event]
+#[derive(Clone)]
+pub struct MarketCancelled {
+    pub market: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub bond_returned: u64, // Returned to each of proposer and disputer; 0 if nothing was proposed
+    pub timestamp: i64,
+}
+
+/// Emitted by `close_market` once a settled market's accounts are closed
+#[event]
+#[derive(Clone)]
+pub struct MarketReclaimed {
+    pub market: Pubkey,
+    pub polymarket_market_id: String,
+    pub swept: u64, // Residual vault balance moved to the fee vault
+    pub timestamp: i64,
+}
+
+/// Emitted by `open_position`
+#[event]
+#[derive(Clone)]
+pub struct PositionOpened {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub position_id: u64,
+    pub direction: Direction,
+    pub size: u64,
+    pub forward_price: u64,
+    pub premium: i64,
+    pub collateral: u64,
+    pub fee: u64,
+    pub referrer: Option<Pubkey>,
+    pub oracle_price: u64,
+    pub oracle_timestamp: i64,
+    pub total_long_exposure: u64,  // After the trade
+    pub total_short_exposure: u64, // After the trade
+    pub timestamp: i64,
+}
+
+/// Emitted by `increase_position`
+#[event]
+#[derive(Clone)]
+pub struct PositionIncreased {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub direction: Direction,
+    pub size: u64,          // Added size
+    pub forward_price: u64, // K for the added size
+    pub premium: i64,
+    pub collateral: u64,
+    pub fee: u64,
+    pub referrer: Option<Pubkey>,
+    pub new_size: u64,
+    pub new_forward_price: u64, // Size-weighted average K
+    pub oracle_price: u64,
+    pub oracle_timestamp: i64,
+    pub total_long_exposure: u64,
+    pub total_short_exposure: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `close_position` and `reduce_position`
+#[event]
+#[derive(Clone)]
+pub struct PositionClosed {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub direction: Direction,
+    pub size: u64, // Exited size
+    pub exit_price: u64,
+    pub payout: u64, // Before the fee
+    pub fee: u64,
+    pub released_collateral: u64,
+    pub remaining_size: u64, // 0 once fully closed
+    pub oracle_price: u64,
+    pub oracle_timestamp: i64,
+    pub total_long_exposure: u64,
+    pub total_short_exposure: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `settle_position` and `crank_settle`
+#[event]
+#[derive(Clone)]
+pub struct PositionSettled {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub direction: Direction,
+    pub size: u64,
+    pub forward_price: u64,
+    pub payout_fraction: u64,
+    pub payout: u64,
+    pub total_long_exposure: u64,
+    pub total_short_exposure: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `refund_position` in a cancelled market
+#[event]
+#[derive(Clone)]
+pub struct PositionRefunded {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub market: Pubkey,
+    pub direction: Direction,
+    pub size: u64,
+    pub collateral: u64,
+    pub premium_paid: i64,
+    pub refund: i64, // Collateral + premium paid; negative when clawed back from the user
+    pub total_long_exposure: u64,
+    pub total_short_exposure: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted once per `crank_settle` batch, after its `PositionSettled` events
+#[event]
+#[derive(Clone)]
+pub struct CrankSettled {
+    pub market: Pubkey,
+    pub cranker: Pubkey,
+    pub settled: u64, // Positions settled; already-settled ones are skipped
+    pub cranker_fee: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_price_oracle`
+#[event]
+#[derive(Clone)]
+pub struct PriceUpdated {
+    pub market: Pubkey,
+    pub price: u64,
+    pub exponent: i8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `propose_resolution`
+#[event]
+#[derive(Clone)]
+pub struct ResolutionProposed {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub payout_fraction: u64,
+    pub bond: u64,
+    pub dispute_deadline: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `dispute_resolution`
+#[event]
+#[derive(Clone)]
+pub struct ResolutionDisputed {
+    pub market: Pubkey,
+    pub disputer: Pubkey,
+    pub proposed_payout_fraction: u64,
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a market's outcome becomes final
+#[event]
+#[derive(Clone)]
+pub struct MarketResolved {
+    pub market: Pubkey,
+    pub payout_fraction: u64,
+    pub disputed: bool, // Settled by arbitration rather than after the liveness period
+    pub timestamp: i64,
+}
+
+/// Emitted by `deposit_liquidity`
+#[event]
+#[derive(Clone)]
+pub struct LiquidityDeposited {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub shares: u64, // LP shares minted
+    pub lp_nav: u64, // After the deposit
+    pub timestamp: i64,
+}
+
+/// Emitted by `withdraw_liquidity`
+#[event]
+#[derive(Clone)]
+pub struct LiquidityWithdrawn {
+    pub market: Pubkey,
+    pub provider: Pubkey,
+    pub shares: u64, // LP shares burned
+    pub amount: u64,
+    pub lp_nav: u64, // After the withdrawal
+    pub timestamp: i64,
+}
+
+/// Emitted by `withdraw_fees`
+#[event]
+#[derive(Clone)]
+pub struct FeesWithdrawn {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `claim_referral_fees`
+#[event]
+#[derive(Clone)]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey, // Referrer authority
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `close_settled_position`
+#[event]
+#[derive(Clone)]
+pub struct PositionAccountClosed {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub status: PositionStatus, // Settled, closed or cancelled
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_fee_bps` and `update_market_fee_bps`
+#[event]
+#[derive(Clone)]
+pub struct FeeUpdated {
+    pub market: Option<Pubkey>, // None for the global fee
+    pub fee_bps: Option<u64>,   // None removes the market override
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_crank_fee`
+#[event]
+#[derive(Clone)]
+pub struct CrankFeeUpdated {
+    pub crank_fee: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `set_referral_tier`
+#[event]
+#[derive(Clone)]
+pub struct ReferralTierUpdated {
+    pub tier: u8,
+    pub share_bps: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `set_referrer_tier`
+#[event]
+#[derive(Clone)]
+pub struct ReferrerTierUpdated {
+    pub referrer: Pubkey, // Referrer authority
+    pub tier: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `set_role`
+#[event]
+#[derive(Clone)]
+pub struct RoleUpdated {
+    pub role: Role,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `propose_admin`
+#[event]
+#[derive(Clone)]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>, // None cancels a pending proposal
+    pub timestamp: i64,
+}
+
+/// Emitted by `accept_admin` and `renounce_admin`
+#[event]
+#[derive(Clone)]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey, // Default pubkey once renounced
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_resolution_params`
+#[event]
+#[derive(Clone)]
+pub struct ResolutionParamsUpdated {
+    pub resolution_params: ResolutionParams, // Applies to proposals made from now on
+    pub timestamp: i64,
+}
+
+/// Emitted by `register_referrer`
+#[event]
+#[derive(Clone)]
+pub struct ReferrerRegistered {
+    pub referrer: Pubkey, // Referrer authority
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_multisig`
+#[event]
+#[derive(Clone)]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted by `update_multisig`
+#[event]
+#[derive(Clone)]
+pub struct MultisigUpdated {
+    pub multisig: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub config_version: u64, // Proposals made under earlier versions are stale
+    pub timestamp: i64,
+}
+
+/// Emitted by `create_proposal`
+#[event]
+#[derive(Clone)]
+pub struct ProposalCreated {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey, // Counts as the first approval
+    pub timestamp: i64,
+}
+
+/// Emitted by `approve_proposal`
+#[event]
+#[derive(Clone)]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub member: Pubkey,
+    pub approvals: u8, // Approvals so far, including this one
+    pub timestamp: i64,
+}
+
+/// Emitted by `execute_proposal` once the proposed instruction has run
+#[event]
+#[derive(Clone)]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
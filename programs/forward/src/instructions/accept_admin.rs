@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::errors::ForwardError;
+use crate::events::AdminTransferred;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut)]
+    pub new_admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        constraint = global_config.pending_admin == Some(new_admin.key()) @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_admin = global_config.admin;
+    global_config.admin = ctx.accounts.new_admin.key();
+    global_config.pending_admin = None;
+    
+    emit_cpi!(AdminTransferred {
+        previous_admin,
+        new_admin: global_config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Admin transferred to {}", global_config.admin);
+    
+    Ok(())
+}
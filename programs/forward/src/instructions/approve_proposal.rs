@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{Multisig, Proposal};
+use crate::errors::ForwardError;
+use crate::events::ProposalApproved;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub member: Signer<'info>,
+    
+    #[account(
+        seeds = [b"multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    #[account(
+        mut,
+        has_one = multisig,
+        constraint = !proposal.executed @ ForwardError::ProposalAlreadyExecuted,
+        constraint = proposal.config_version == multisig.config_version @ ForwardError::ProposalStale
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handler(ctx: Context<ApproveProposal>) -> Result<()> {
+    let member_index = ctx
+        .accounts
+        .multisig
+        .member_index(&ctx.accounts.member.key())
+        .ok_or(ForwardError::NotMultisigMember)?;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approvals[member_index] = true;
+    
+    emit_cpi!(ProposalApproved {
+        proposal: proposal.key(),
+        member: ctx.accounts.member.key(),
+        approvals: proposal.approvals.iter().filter(|approved| **approved).count() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Proposal approved by {}", ctx.accounts.member.key());
+    
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::errors::ForwardError;
+use crate::events::CurveParamsUpdateCancelled;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelCurveParamsUpdate<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<CancelCurveParamsUpdate>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    
+    // Once active the change is already in effect; persist it instead
+    let clock = Clock::get()?;
+    global_config.apply_pending_curve_params(clock.unix_timestamp);
+    require!(
+        global_config.pending_curve_params.is_some(),
+        ForwardError::InvalidCurveParamsUpdate
+    );
+    
+    global_config.pending_curve_params = None;
+    
+    emit_cpi!(CurveParamsUpdateCancelled {
+        market: None,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Curve parameters update cancelled");
+    
+    Ok(())
+}
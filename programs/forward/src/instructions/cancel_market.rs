@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::MarketStatus;
+use crate::oracle::ResolutionStatus;
+use crate::errors::ForwardError;
+use crate::events::MarketCancelled;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelMarket<'info> {
+    /// Admin, or the resolution keeper once an outcome has been proposed
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"resolution_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub resolution_oracle: Account<'info, crate::oracle::ResolutionOracle>,
+
+    /// Escrow holding the bonds; required once an outcome has been proposed
+    #[account(
+        mut,
+        seeds = [b"bond_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = proposer_collateral_account.owner == resolution_oracle.proposer @ ForwardError::Unauthorized
+    )]
+    pub proposer_collateral_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = disputer_collateral_account.owner == resolution_oracle.disputer @ ForwardError::Unauthorized
+    )]
+    pub disputer_collateral_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelMarket>) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active
+            || ctx.accounts.market_config.status == MarketStatus::TradingClosed,
+        ForwardError::InvalidMarketStatus
+    );
+
+    // The admin cancels markets nobody has proposed an outcome for. Once an
+    // outcome is proposed the market is in the resolution keeper's hands: it
+    // can void the market and return the bonds posted so far, whether or not
+    // the proposal has been disputed, so a market voided upstream doesn't have
+    // to wait for a dispute or let a wrong outcome finalize.
+    let authority = ctx.accounts.authority.key();
+    let bond_returned = match ctx.accounts.resolution_oracle.status {
+        ResolutionStatus::Unproposed => {
+            require!(
+                authority == ctx.accounts.global_config.admin,
+                ForwardError::Unauthorized
+            );
+            0
+        }
+        ResolutionStatus::Proposed | ResolutionStatus::Disputed => {
+            require!(
+                authority == ctx.accounts.global_config.resolution_keeper,
+                ForwardError::Unauthorized
+            );
+            let disputed = ctx.accounts.resolution_oracle.status == ResolutionStatus::Disputed;
+            return_bonds(&ctx, disputed)?;
+
+            // The bonds are back with their owners: clear the oracle's record
+            // of them and end its lifecycle so nothing can act on the
+            // voided proposal
+            let resolution_oracle = &mut ctx.accounts.resolution_oracle;
+            let bond = resolution_oracle.bond;
+            resolution_oracle.bond = 0;
+            resolution_oracle.status = ResolutionStatus::Cancelled;
+            bond
+        }
+        _ => return err!(ForwardError::InvalidResolutionState),
+    };
+
+    let market_config = &mut ctx.accounts.market_config;
+    market_config.status = MarketStatus::Cancelled;
+
+    emit_cpi!(MarketCancelled {
+        market: market_config.key(),
+        cancelled_by: authority,
+        bond_returned,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Market cancelled: {}", market_config.polymarket_market_id);
+
+    Ok(())
+}
+
+/// Return the proposer's bond, and the disputer's if `disputed`, from the bond vault
+fn return_bonds(ctx: &Context<CancelMarket>, disputed: bool) -> Result<()> {
+    let bond = ctx.accounts.resolution_oracle.bond;
+    if bond == 0 {
+        return Ok(());
+    }
+
+    let bond_vault = ctx
+        .accounts
+        .bond_vault
+        .as_ref()
+        .ok_or(ForwardError::BondAccountsRequired)?;
+    let market_config_key = ctx.accounts.market_config.key();
+    let seeds = &[
+        b"bond_vault",
+        market_config_key.as_ref(),
+        &[ctx.bumps.bond_vault.ok_or(ForwardError::BondAccountsRequired)?],
+    ];
+    let signer = &[&seeds[..]];
+
+    let recipients = [
+        &ctx.accounts.proposer_collateral_account,
+        &ctx.accounts.disputer_collateral_account,
+    ];
+    for recipient in &recipients[..if disputed { 2 } else { 1 }] {
+        let recipient = recipient
+            .as_ref()
+            .ok_or(ForwardError::BondAccountsRequired)?;
+        require!(
+            recipient.mint == bond_vault.mint,
+            ForwardError::InvalidMint
+        );
+
+        let cpi_accounts = Transfer {
+            from: bond_vault.to_account_info(),
+            to: recipient.to_account_info(),
+            authority: bond_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, bond)?;
+    }
+
+    Ok(())
+}
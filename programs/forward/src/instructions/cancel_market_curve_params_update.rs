@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::errors::ForwardError;
+use crate::events::CurveParamsUpdateCancelled;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelMarketCurveParamsUpdate<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+}
+
+pub fn handler(ctx: Context<CancelMarketCurveParamsUpdate>) -> Result<()> {
+    let market_config = &mut ctx.accounts.market_config;
+    
+    // Once active the change is already in effect; persist it instead
+    let clock = Clock::get()?;
+    market_config.apply_pending_curve_params(clock.unix_timestamp);
+    require!(
+        market_config.pending_curve_params.is_some(),
+        ForwardError::InvalidCurveParamsUpdate
+    );
+    
+    market_config.pending_curve_params = None;
+    
+    emit_cpi!(CurveParamsUpdateCancelled {
+        market: Some(market_config.key()),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!(
+        "Market curve parameters update cancelled for {}",
+        market_config.polymarket_market_id
+    );
+    
+    Ok(())
+}
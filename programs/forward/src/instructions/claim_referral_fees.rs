@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{FeeLedger, GlobalConfig, Referrer};
+use crate::errors::ForwardError;
+use crate::events::ReferralFeesClaimed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_ledger"],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Account<'info, FeeLedger>,
+    
+    #[account(
+        mut,
+        seeds = [b"referrer", authority.key().as_ref()],
+        bump = referrer.bump,
+        has_one = authority @ ForwardError::Unauthorized
+    )]
+    pub referrer: Account<'info, Referrer>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let amount = ctx.accounts.referrer.claimable()?;
+    require!(amount > 0, ForwardError::NoReferralFeesToClaim);
+    
+    let seeds = &[b"fee_vault".as_ref(), &[ctx.bumps.fee_vault]];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.fee_vault.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: ctx.accounts.fee_vault.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+    
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.claimed = referrer.accrued;
+    let fee_ledger = &mut ctx.accounts.fee_ledger;
+    fee_ledger.referral_fees_owed = fee_ledger
+        .referral_fees_owed
+        .checked_sub(amount)
+        .ok_or(ForwardError::MathOverflow)?;
+    
+    emit_cpi!(ReferralFeesClaimed {
+        referrer: ctx.accounts.authority.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Referrer {} claimed {} in fees", referrer.authority, amount);
+    
+    Ok(())
+}
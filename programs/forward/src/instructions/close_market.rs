@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::MarketStatus;
+use crate::oracle::ResolutionStatus;
+use crate::errors::ForwardError;
+use crate::events::MarketReclaimed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMarket<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"price_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub price_oracle: Account<'info, crate::oracle::PriceOracle>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"resolution_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub resolution_oracle: Account<'info, crate::oracle::ResolutionOracle>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// Bond escrow; exists once an outcome has been proposed
+    #[account(
+        mut,
+        seeds = [b"bond_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"lp_mint", market_config.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CloseMarket>) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Resolved || ctx.accounts.market_config.status == MarketStatus::Cancelled,
+        ForwardError::InvalidMarketStatus
+    );
+
+    // Every position must be settled or refunded and every LP share burned
+    let pool_state = &ctx.accounts.pool_state;
+    require!(
+        pool_state.total_long_exposure == 0 && pool_state.total_short_exposure == 0,
+        ForwardError::MarketNotEmpty
+    );
+    require!(
+        ctx.accounts.lp_mint.supply == 0,
+        ForwardError::MarketNotEmpty
+    );
+
+    // Nobody has a claim on what is left in the vaults (rounding dust or stray
+    // transfers), so sweep it to the fee vault and close them
+    let market_config_key = ctx.accounts.market_config.key();
+    let mut swept = sweep_and_close(
+        &ctx.accounts.token_program,
+        &ctx.accounts.collateral_vault,
+        &[b"collateral_vault", market_config_key.as_ref(), &[ctx.bumps.collateral_vault]],
+        &ctx.accounts.fee_vault,
+        &ctx.accounts.admin,
+    )?;
+
+    // Bonds are paid out on finalization, arbitration or cancellation, but the
+    // escrow outlives the proposal and must not be orphaned with the market
+    if ctx.accounts.resolution_oracle.status != ResolutionStatus::Unproposed {
+        let bond_vault = ctx
+            .accounts
+            .bond_vault
+            .as_ref()
+            .ok_or(ForwardError::BondVaultRequired)?;
+        let bond_swept = sweep_and_close(
+            &ctx.accounts.token_program,
+            bond_vault,
+            &[
+                b"bond_vault",
+                market_config_key.as_ref(),
+                &[ctx.bumps.bond_vault.ok_or(ForwardError::BondVaultRequired)?],
+            ],
+            &ctx.accounts.fee_vault,
+            &ctx.accounts.admin,
+        )?;
+        swept = swept
+            .checked_add(bond_swept)
+            .ok_or(ForwardError::MathOverflow)?;
+    }
+
+    emit_cpi!(MarketReclaimed {
+        market: market_config_key,
+        polymarket_market_id: ctx.accounts.market_config.polymarket_market_id.clone(),
+        swept,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // The LP mint cannot be closed, so the market ID can never be reused
+    msg!(
+        "Market closed: {} (swept {} to fee vault)",
+        ctx.accounts.market_config.polymarket_market_id,
+        swept
+    );
+
+    Ok(())
+}
+
+/// Move a market vault's balance to the fee vault and close it, returning the
+/// rent to the admin; a token account must be empty to close
+fn sweep_and_close<'info>(
+    token_program: &Program<'info, Token>,
+    vault: &Account<'info, TokenAccount>,
+    seeds: &[&[u8]],
+    fee_vault: &Account<'info, TokenAccount>,
+    admin: &Signer<'info>,
+) -> Result<u64> {
+    let signer = &[seeds];
+
+    let residual = vault.amount;
+    if residual > 0 {
+        let cpi_accounts = Transfer {
+            from: vault.to_account_info(),
+            to: fee_vault.to_account_info(),
+            authority: vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, residual)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: vault.to_account_info(),
+        destination: admin.to_account_info(),
+        authority: vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token::close_account(cpi_ctx)?;
+
+    Ok(residual)
+}
@@ -1,16 +1,18 @@
 use anchor_lang::prelude::*;
 use crate::state::MarketStatus;
 use crate::errors::ForwardError;
+use crate::events::MarketClosed;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseMarketForTrading<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub risk_manager: Signer<'info>,
     
     #[account(
         seeds = [b"global_config"],
         bump = global_config.bump,
-        has_one = admin @ ForwardError::Unauthorized
+        has_one = risk_manager @ ForwardError::Unauthorized
     )]
     pub global_config: Account<'info, crate::state::GlobalConfig>,
     
@@ -32,6 +34,12 @@ pub fn handler(ctx: Context<CloseMarketForTrading>) -> Result<()> {
     
     market_config.status = MarketStatus::TradingClosed;
     
+    emit_cpi!(MarketClosed {
+        market: market_config.key(),
+        closed_by: ctx.accounts.risk_manager.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     msg!("Market closed for trading");
     
     Ok(())
@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{check_deadline, CloseQuote, MarketStatus, PositionStatus};
+use crate::math;
+use crate::errors::ForwardError;
+use crate::oracle;
+use crate::events::PositionClosed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        seeds = [b"price_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub price_oracle: Account<'info, crate::oracle::PriceOracle>,
+
+    #[account(
+        mut,
+        constraint = position.owner == user.key(),
+        constraint = position.market == market_config.key(),
+        constraint = position.status == PositionStatus::Open @ ForwardError::PositionAlreadySettled
+    )]
+    pub position: Account<'info, crate::state::Position>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_collateral_account.owner == user.key(),
+        constraint = user_collateral_account.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ClosePosition>,
+    min_payout: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+
+    let curve_params = ctx
+        .accounts
+        .market_config
+        .active_curve_params(&ctx.accounts.global_config, now);
+
+    // Read Polymarket price from oracle
+    let polymarket_price = oracle::read_price(&ctx.accounts.price_oracle)?;
+    require!(polymarket_price > 0, ForwardError::InvalidOracleData);
+
+    let size = ctx.accounts.position.size;
+    let direction = ctx.accounts.position.direction;
+
+    // Price the exit over the exposure path the close unwinds and release
+    // everything locked against the position (same math as quote_close)
+    let quote = math::quote_close(
+        polymarket_price,
+        &ctx.accounts.pool_state,
+        &curve_params,
+        &ctx.accounts.position,
+        ctx.accounts.market_config.effective_fee_bps(&ctx.accounts.global_config),
+        size,
+    )?;
+    let CloseQuote {
+        exit_price,
+        payout,
+        fee,
+        user_payout,
+        released_collateral,
+        ..
+    } = quote;
+
+    // Slippage protection: the user receives at least what they signed for
+    require!(user_payout >= min_payout, ForwardError::MinPayoutNotMet);
+
+    // Pay the user and the fee out of the vault
+    let market_config_key = ctx.accounts.market_config.key();
+    transfer_close_payout(
+        &ctx.accounts.token_program,
+        &ctx.accounts.collateral_vault,
+        &[b"collateral_vault", market_config_key.as_ref(), &[ctx.bumps.collateral_vault]],
+        &ctx.accounts.user_collateral_account,
+        &ctx.accounts.fee_vault,
+        &quote,
+    )?;
+
+    // Remove the exited size from the pool; LPs keep the released collateral
+    // and fund everything paid out of the vault
+    ctx.accounts.pool_state.unwind(
+        direction,
+        size,
+        quote.pool_collateral,
+        released_collateral,
+        payout,
+    )?;
+
+    // Mark position as closed
+    ctx.accounts.position.status = PositionStatus::Closed;
+
+    let position = &ctx.accounts.position;
+    emit_cpi!(PositionClosed {
+        position: position.key(),
+        owner: position.owner,
+        market: position.market,
+        direction,
+        size,
+        exit_price,
+        payout,
+        fee,
+        released_collateral,
+        remaining_size: 0,
+        oracle_price: polymarket_price,
+        oracle_timestamp: ctx.accounts.price_oracle.timestamp,
+        total_long_exposure: ctx.accounts.pool_state.total_long_exposure,
+        total_short_exposure: ctx.accounts.pool_state.total_short_exposure,
+        timestamp: now,
+    });
+
+    msg!(
+        "Position closed: exit K={}, entry K={}, payout={}, fee={}, size={}",
+        exit_price,
+        ctx.accounts.position.forward_price,
+        payout,
+        fee,
+        size
+    );
+
+    Ok(())
+}
+
+/// Pay out an exit from the market vault
+///
+/// The user receives `user_payout` and the fee goes to the fee vault; together
+/// they are the payout.
+pub(crate) fn transfer_close_payout<'info>(
+    token_program: &Program<'info, Token>,
+    collateral_vault: &Account<'info, TokenAccount>,
+    collateral_vault_seeds: &[&[u8]],
+    user_collateral_account: &Account<'info, TokenAccount>,
+    fee_vault: &Account<'info, TokenAccount>,
+    quote: &CloseQuote,
+) -> Result<()> {
+    require!(
+        collateral_vault.amount
+            >= quote
+                .user_payout
+                .checked_add(quote.fee)
+                .ok_or(ForwardError::MathOverflow)?,
+        ForwardError::InsufficientCollateral
+    );
+
+    let signer = &[collateral_vault_seeds];
+
+    if quote.user_payout > 0 {
+        let cpi_accounts = Transfer {
+            from: collateral_vault.to_account_info(),
+            to: user_collateral_account.to_account_info(),
+            authority: collateral_vault.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, quote.user_payout)?;
+    }
+
+    if quote.fee > 0 {
+        let cpi_accounts = Transfer {
+            from: collateral_vault.to_account_info(),
+            to: fee_vault.to_account_info(),
+            authority: collateral_vault.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, quote.fee)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{CurveBreakpoint, CurveKind, CurveParams, Direction, PoolState, Position};
+
+    fn curve() -> CurveParams {
+        CurveParams {
+            alpha: 1000,
+            beta: 200,
+            max_exposure: 10_000_000,
+            min_price: 100,
+            max_price: 9900,
+            kind: CurveKind::Linear,
+            steepness: 0,
+            breakpoint_count: 0,
+            breakpoints: [CurveBreakpoint::default(); CurveParams::MAX_BREAKPOINTS],
+        }
+    }
+
+    /// A short of 1_000_000 entered at K = 0.5, alone in the pool
+    fn short() -> (PoolState, Position) {
+        let position = Position {
+            owner: Pubkey::default(),
+            market: Pubkey::default(),
+            direction: Direction::Short,
+            size: 1_000_000,
+            forward_price: 5000,
+            collateral_locked: 500_000,
+            premium_paid: 0,
+            fee_paid: 1_500,
+            status: PositionStatus::Open,
+            bump: 0,
+        };
+        let pool_state = PoolState {
+            market: Pubkey::default(),
+            total_long_exposure: 0,
+            total_short_exposure: position.size,
+            pool_collateral: position.size - position.collateral_locked,
+            position_counter: 1,
+            lp_nav: 2_000_000,
+            bump: 0,
+        };
+        (pool_state, position)
+    }
+
+    /// Close `position` at `price`, returning the quote and the pool after it
+    fn close(price: u64, fee_bps: u64) -> (CloseQuote, PoolState) {
+        let (mut pool_state, position) = short();
+        let quote = math::quote_close(price, &pool_state, &curve(), &position, fee_bps, position.size).unwrap();
+        pool_state
+            .unwind(
+                position.direction,
+                position.size,
+                quote.pool_collateral,
+                quote.released_collateral,
+                quote.payout,
+            )
+            .unwrap();
+        (quote, pool_state)
+    }
+
+    #[test]
+    fn close_fee_is_paid_out_of_the_payout() {
+        let (free, without_fee) = close(3000, 0);
+        let (charged, with_fee) = close(3000, 100);
+        assert!(charged.fee > 0);
+
+        // The vault pays out the same either way; the fee only changes who gets it
+        assert_eq!(charged.payout, free.payout);
+        assert_eq!(charged.user_payout + charged.fee, charged.payout);
+        assert_eq!(with_fee.lp_nav, without_fee.lp_nav);
+    }
+
+    #[test]
+    fn losing_close_fee_is_capped_so_lps_never_fund_it() {
+        // Near YES a short is worth little: a 10% fee on Q·K exceeds its payout
+        let (quote, pool_state) = close(9800, 1000);
+        assert!(math::calculate_fee(quote.exit_price, 1_000_000, 1000).unwrap() > quote.payout);
+        assert_eq!(quote.fee, quote.payout);
+        assert_eq!(quote.user_payout, 0);
+
+        // LPs keep the released collateral minus the payout, as without a fee
+        let (_, without_fee) = close(9800, 0);
+        assert_eq!(
+            pool_state.lp_nav,
+            2_000_000 + quote.released_collateral - quote.payout
+        );
+        assert_eq!(pool_state.lp_nav, without_fee.lp_nav);
+        assert_eq!(pool_state.pool_collateral, 0);
+    }
+}
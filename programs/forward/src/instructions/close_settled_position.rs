@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::PositionStatus;
+use crate::errors::ForwardError;
+use crate::events::PositionAccountClosed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSettledPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Any position that can no longer pay out: settled, closed early or refunded
+    #[account(
+        mut,
+        close = user,
+        constraint = position.owner == user.key() @ ForwardError::Unauthorized,
+        constraint = position.status != PositionStatus::Open @ ForwardError::PositionStillOpen
+    )]
+    pub position: Account<'info, crate::state::Position>,
+}
+
+pub fn handler(ctx: Context<CloseSettledPosition>) -> Result<()> {
+    emit_cpi!(PositionAccountClosed {
+        position: ctx.accounts.position.key(),
+        owner: ctx.accounts.position.owner,
+        status: ctx.accounts.position.status.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Position account closed: {} ({:?})",
+        ctx.accounts.position.key(),
+        ctx.accounts.position.status
+    );
+
+    Ok(())
+}
@@ -0,0 +1,408 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use super::settle_position::transfer_settlement_payout;
+use crate::state::{GlobalConfig, MarketStatus, Position, PositionStatus};
+use crate::math::{self, BASIS_POINTS};
+use crate::errors::ForwardError;
+use crate::events::{CrankSettled, PositionSettled};
+
+/// Settle positions on behalf of their owners after resolution
+///
+/// `remaining_accounts` holds `(position, owner_collateral_account)` pairs,
+/// both writable. Positions that are no longer open, or whose accounts have
+/// been closed, are skipped so a batch does not fail when an owner settles
+/// first, or lists a position twice.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CrankSettle<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        seeds = [b"resolution_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub resolution_oracle: Account<'info, crate::oracle::ResolutionOracle>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"fee_ledger"],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Account<'info, crate::state::FeeLedger>,
+
+    /// Receives the crank fee; omit to crank for free
+    #[account(
+        mut,
+        constraint = cranker_collateral_account.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub cranker_collateral_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// `usize::is_multiple_of` needs Rust 1.87, newer than the SBF toolchain
+#[allow(clippy::manual_is_multiple_of)]
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CrankSettle<'info>>) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Resolved,
+        ForwardError::MarketNotResolved
+    );
+
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+        ForwardError::InvalidCrankAccounts
+    );
+
+    let outcome = crate::oracle::read_resolution(&ctx.accounts.resolution_oracle)?
+        .ok_or(ForwardError::InvalidOracleData)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    let market_config_key = ctx.accounts.market_config.key();
+    let vault_seeds = &[
+        b"collateral_vault",
+        market_config_key.as_ref(),
+        &[ctx.bumps.collateral_vault],
+    ];
+
+    let crank_fee = ctx.accounts.global_config.crank_fee;
+
+    let mut settled: u64 = 0;
+    let mut cranker_fee_due: u64 = 0;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let owner_account_info = &pair[1];
+        let Some(mut position) = load_crank_pair(
+            &pair[0],
+            owner_account_info,
+            &market_config_key,
+            &ctx.accounts.collateral_vault.mint,
+        )?
+        else {
+            continue;
+        };
+
+        let payout = math::calculate_settlement_payout(position.size, position.direction, outcome);
+        transfer_settlement_payout(
+            &ctx.accounts.token_program,
+            &ctx.accounts.collateral_vault,
+            vault_seeds,
+            owner_account_info.clone(),
+            payout,
+        )?;
+        // Keep the balance the next payout is checked against current
+        ctx.accounts.collateral_vault.reload()?;
+
+        ctx.accounts.pool_state.settle(&position, payout)?;
+        position.status = PositionStatus::Settled;
+        position.exit(&crate::ID)?;
+
+        emit_cpi!(PositionSettled {
+            position: position.key(),
+            owner: position.owner,
+            market: position.market,
+            direction: position.direction,
+            size: position.size,
+            forward_price: position.forward_price,
+            payout_fraction: outcome.payout_fraction(),
+            payout,
+            total_long_exposure: ctx.accounts.pool_state.total_long_exposure,
+            total_short_exposure: ctx.accounts.pool_state.total_short_exposure,
+            timestamp: now,
+        });
+
+        settled += 1;
+        cranker_fee_due = cranker_fee_due
+            .checked_add(position_crank_fee(&position, crank_fee))
+            .ok_or(ForwardError::MathOverflow)?;
+    }
+
+    // The crank fee comes out of protocol fees, never out of referrers' claims;
+    // if the vault runs short the cranker is paid what is available
+    let mut cranker_fee = 0;
+    if let Some(cranker_collateral_account) = &ctx.accounts.cranker_collateral_account {
+        let withdrawable = ctx
+            .accounts
+            .fee_ledger
+            .protocol_fees(ctx.accounts.fee_vault.amount);
+        cranker_fee = cranker_fee_due.min(withdrawable);
+
+        if cranker_fee > 0 {
+            let seeds = &[b"fee_vault".as_ref(), &[ctx.bumps.fee_vault]];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.fee_vault.to_account_info(),
+                to: cranker_collateral_account.to_account_info(),
+                authority: ctx.accounts.fee_vault.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, cranker_fee)?;
+        }
+    }
+
+    emit_cpi!(CrankSettled {
+        market: market_config_key,
+        cranker: ctx.accounts.cranker.key(),
+        settled,
+        cranker_fee,
+        timestamp: now,
+    });
+
+    msg!(
+        "Crank settled {} positions: outcome={:?}, cranker fee={}",
+        settled,
+        outcome,
+        cranker_fee
+    );
+
+    Ok(())
+}
+
+/// Check a `(position, owner_collateral_account)` pair and load the position,
+/// or `None` if it is no longer open or its account has already been closed
+fn load_crank_pair<'info>(
+    position_info: &'info AccountInfo<'info>,
+    owner_account_info: &'info AccountInfo<'info>,
+    market: &Pubkey,
+    collateral_mint: &Pubkey,
+) -> Result<Option<Account<'info, Position>>> {
+    require!(
+        position_info.is_writable && owner_account_info.is_writable,
+        ForwardError::InvalidCrankAccounts
+    );
+
+    // Settling with `close = true` or `close_settled_position` hands the
+    // account back to the system program with no data
+    if position_info.owner != &crate::ID || position_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let position = Account::<Position>::try_from(position_info)?;
+    require!(
+        position.market == *market,
+        ForwardError::InvalidCrankAccounts
+    );
+    if position.status != PositionStatus::Open {
+        return Ok(None);
+    }
+
+    let owner_account = Account::<TokenAccount>::try_from(owner_account_info)?;
+    require!(
+        owner_account.owner == position.owner,
+        ForwardError::Unauthorized
+    );
+    require!(
+        owner_account.mint == *collateral_mint,
+        ForwardError::InvalidMint
+    );
+
+    Ok(Some(position))
+}
+
+/// Crank fee for settling `position`, capped at a share of the trading fee it
+/// paid to open so splitting into dust positions cannot drain the fee vault
+fn position_crank_fee(position: &Position, crank_fee: u64) -> u64 {
+    let cap = position.fee_paid as u128 * GlobalConfig::CRANK_FEE_SHARE_BPS as u128 / BASIS_POINTS as u128;
+    crank_fee.min(cap as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Direction;
+    use crate::test_utils::StoredAccount;
+
+    fn stored_position(market: Pubkey, owner: Pubkey, size: u64) -> StoredAccount {
+        StoredAccount::new(&position(market, owner, size))
+    }
+
+    fn position(market: Pubkey, owner: Pubkey, size: u64) -> Position {
+        Position {
+            owner,
+            market,
+            direction: Direction::Long,
+            size,
+            forward_price: 5000,
+            collateral_locked: size / 2,
+            premium_paid: 0,
+            fee_paid: size * 15 / 10_000,
+            status: PositionStatus::Open,
+            bump: 255,
+        }
+    }
+
+    fn error_code(result: Result<Option<Account<Position>>>) -> u32 {
+        match result {
+            Err(Error::AnchorError(error)) => error.error_code_number,
+            Err(error) => panic!("unexpected error: {error:?}"),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    struct Batch {
+        market: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+    }
+
+    impl Batch {
+        fn new() -> Self {
+            Self {
+                market: Pubkey::new_unique(),
+                mint: Pubkey::new_unique(),
+                owner: Pubkey::new_unique(),
+            }
+        }
+    }
+
+    #[test]
+    fn loads_open_position_with_its_owners_account() {
+        let batch = Batch::new();
+        let mut position = stored_position(batch.market, batch.owner, 1_000_000);
+        let mut owner_account = StoredAccount::token_account(batch.mint, batch.owner, 0);
+        let (position_info, owner_info) = (position.info(true), owner_account.info(true));
+
+        let loaded = load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint)
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.key(), position.key);
+    }
+
+    #[test]
+    fn duplicate_position_in_batch_is_skipped() {
+        let batch = Batch::new();
+        let mut position = stored_position(batch.market, batch.owner, 1_000_000);
+        let mut owner_account = StoredAccount::token_account(batch.mint, batch.owner, 0);
+        let (position_info, owner_info) = (position.info(true), owner_account.info(true));
+
+        // The handler marks each position settled and writes it back before
+        // loading the next pair, so a second listing sees it closed
+        let mut first = load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint)
+            .unwrap()
+            .unwrap();
+        first.status = PositionStatus::Settled;
+        first.exit(&crate::ID).unwrap();
+
+        let second = load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint).unwrap();
+        assert!(second.is_none());
+    }
+
+    #[test]
+    fn closed_position_in_batch_is_skipped() {
+        let batch = Batch::new();
+        let mut position = StoredAccount {
+            key: Pubkey::new_unique(),
+            owner: anchor_lang::system_program::ID,
+            lamports: 0,
+            data: Vec::new(),
+        };
+        let mut owner_account = StoredAccount::token_account(batch.mint, batch.owner, 0);
+        let (position_info, owner_info) = (position.info(true), owner_account.info(true));
+
+        let loaded = load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint).unwrap();
+        assert!(loaded.is_none());
+    }
+
+    #[test]
+    fn rejects_position_from_another_market() {
+        let batch = Batch::new();
+        let mut position = stored_position(Pubkey::new_unique(), batch.owner, 1_000_000);
+        let mut owner_account = StoredAccount::token_account(batch.mint, batch.owner, 0);
+        let (position_info, owner_info) = (position.info(true), owner_account.info(true));
+
+        assert_eq!(
+            error_code(load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint)),
+            u32::from(ForwardError::InvalidCrankAccounts)
+        );
+    }
+
+    #[test]
+    fn rejects_token_account_of_another_owner() {
+        let batch = Batch::new();
+        let mut position = stored_position(batch.market, batch.owner, 1_000_000);
+        let mut owner_account = StoredAccount::token_account(batch.mint, Pubkey::new_unique(), 0);
+        let (position_info, owner_info) = (position.info(true), owner_account.info(true));
+
+        assert_eq!(
+            error_code(load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint)),
+            u32::from(ForwardError::Unauthorized)
+        );
+    }
+
+    #[test]
+    fn rejects_token_account_of_another_mint() {
+        let batch = Batch::new();
+        let mut position = stored_position(batch.market, batch.owner, 1_000_000);
+        let mut owner_account = StoredAccount::token_account(Pubkey::new_unique(), batch.owner, 0);
+        let (position_info, owner_info) = (position.info(true), owner_account.info(true));
+
+        assert_eq!(
+            error_code(load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint)),
+            u32::from(ForwardError::InvalidMint)
+        );
+    }
+
+    #[test]
+    fn rejects_read_only_accounts() {
+        let batch = Batch::new();
+        let mut position = stored_position(batch.market, batch.owner, 1_000_000);
+        let mut owner_account = StoredAccount::token_account(batch.mint, batch.owner, 0);
+        let (position_info, owner_info) = (position.info(true), owner_account.info(false));
+
+        assert_eq!(
+            error_code(load_crank_pair(&position_info, &owner_info, &batch.market, &batch.mint)),
+            u32::from(ForwardError::InvalidCrankAccounts)
+        );
+    }
+
+    #[test]
+    fn crank_fee_is_capped_by_the_fee_paid_at_open() {
+        let (market, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        // 30 bps on Q·K = 1_000_000 · 0.5 is 1_500, half of which caps the fee
+        assert_eq!(position_crank_fee(&position(market, owner, 1_000_000), 10_000), 750);
+        assert_eq!(position_crank_fee(&position(market, owner, 1_000_000), 500), 500);
+
+        // A dust position pays a 1-unit trading fee and earns nothing to crank
+        let mut dust = position(market, owner, 1);
+        dust.fee_paid = 1;
+        assert_eq!(position_crank_fee(&dust, 10_000), 0);
+
+        // The cap follows what the position paid, not the market's fee now
+        let mut fee_free = position(market, owner, 1_000_000);
+        fee_free.fee_paid = 0;
+        assert_eq!(position_crank_fee(&fee_free, 10_000), 0);
+    }
+}
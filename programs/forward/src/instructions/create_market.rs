@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use crate::state::{MarketConfig, PoolState, RiskLimits, MarketStatus};
+use crate::state::{CurveParams, MarketConfig, PoolState, RiskLimits, MarketStatus};
 use crate::oracle::{PriceOracle, ResolutionOracle};
+use crate::events::MarketCreated;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(polymarket_market_id: String, resolution_timestamp: i64)]
 pub struct CreateMarket<'info> {
@@ -64,6 +66,16 @@ pub struct CreateMarket<'info> {
     )]
     pub collateral_vault: Account<'info, TokenAccount>,
     
+    #[account(
+        init,
+        payer = admin,
+        mint::decimals = mint.decimals,
+        mint::authority = pool_state,
+        seeds = [b"lp_mint", market_config.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -73,6 +85,8 @@ pub fn handler(
     polymarket_market_id: String,
     resolution_timestamp: i64,
     risk_limits: RiskLimits,
+    curve_params: Option<CurveParams>,
+    fee_bps: Option<u64>,
 ) -> Result<()> {
     require!(
         polymarket_market_id.len() <= MarketConfig::MAX_MARKET_ID_LEN,
@@ -111,6 +125,19 @@ pub fn handler(
         crate::errors::ForwardError::InvalidOracleData
     );
     
+    // Optional per‑market curve override, same invariants as the global curve
+    if let Some(curve_params) = &curve_params {
+        curve_params.validate()?;
+    }
+    
+    // Optional per‑market fee override
+    if let Some(fee_bps) = fee_bps {
+        require!(
+            fee_bps <= crate::state::GlobalConfig::MAX_FEE_BPS,
+            crate::errors::ForwardError::FeeTooHigh
+        );
+    }
+    
     let market_config = &mut ctx.accounts.market_config;
     let pool_state = &mut ctx.accounts.pool_state;
     
@@ -123,6 +150,10 @@ pub fn handler(
     market_config.status = MarketStatus::Active;
     market_config.pool_state = pool_state.key();
     market_config.collateral_vault = ctx.accounts.collateral_vault.key();
+    market_config.lp_mint = ctx.accounts.lp_mint.key();
+    market_config.curve_params = curve_params;
+    market_config.pending_curve_params = None;
+    market_config.fee_bps = fee_bps;
     market_config.bump = ctx.bumps.market_config;
     
     pool_state.market = market_config.key();
@@ -130,8 +161,19 @@ pub fn handler(
     pool_state.total_short_exposure = 0;
     pool_state.pool_collateral = 0;
     pool_state.position_counter = 0;
+    pool_state.lp_nav = 0;
     pool_state.bump = ctx.bumps.pool_state;
     
+    emit_cpi!(MarketCreated {
+        market: market_config.key(),
+        polymarket_market_id: polymarket_market_id.clone(),
+        resolution_timestamp,
+        risk_limits: market_config.risk_limits.clone(),
+        curve_params: market_config.curve_params.clone(),
+        fee_bps,
+        timestamp: clock.unix_timestamp,
+    });
+    
     msg!(
         "Market created: {} with resolution at {}",
         polymarket_market_id,
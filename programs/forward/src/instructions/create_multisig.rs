@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::Multisig;
+use crate::errors::ForwardError;
+use crate::events::MultisigCreated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Multisig::LEN,
+        seeds = [b"multisig"],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateMultisig>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    Multisig::validate_config(&members, threshold)?;
+    
+    let multisig_key = ctx.accounts.multisig.key();
+    let (_, signer_bump) = Pubkey::find_program_address(
+        &[b"multisig_signer", multisig_key.as_ref()],
+        ctx.program_id,
+    );
+    
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.proposal_count = 0;
+    multisig.config_version = 0;
+    multisig.signer_bump = signer_bump;
+    multisig.bump = ctx.bumps.multisig;
+    
+    emit_cpi!(MultisigCreated {
+        multisig: multisig_key,
+        members: multisig.members.clone(),
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!(
+        "Multisig created: {}-of-{}",
+        threshold,
+        multisig.members.len()
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{Multisig, Proposal, ProposalAccount};
+use crate::errors::ForwardError;
+use crate::events::ProposalCreated;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(accounts: Vec<ProposalAccount>, data: Vec<u8>)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::space(accounts.len(), data.len(), multisig.members.len()),
+        seeds = [b"proposal", multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateProposal>,
+    accounts: Vec<ProposalAccount>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let member_index = ctx
+        .accounts
+        .multisig
+        .member_index(&ctx.accounts.proposer.key())
+        .ok_or(ForwardError::NotMultisigMember)?;
+    
+    // Creating a proposal counts as the proposer's approval
+    let mut approvals = vec![false; ctx.accounts.multisig.members.len()];
+    approvals[member_index] = true;
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = ctx.accounts.multisig.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.accounts = accounts;
+    proposal.data = data;
+    proposal.approvals = approvals;
+    proposal.config_version = ctx.accounts.multisig.config_version;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+    
+    let multisig = &mut ctx.accounts.multisig;
+    let index = multisig.proposal_count;
+    multisig.proposal_count = index
+        .checked_add(1)
+        .ok_or(ForwardError::MathOverflow)?;
+    
+    emit_cpi!(ProposalCreated {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        index,
+        proposer: proposal.proposer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Proposal {} created", index);
+    
+    Ok(())
+}
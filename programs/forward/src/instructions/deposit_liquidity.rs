@@ -0,0 +1,400 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::{MarketStatus, PoolState};
+use crate::errors::ForwardError;
+use crate::events::LiquidityDeposited;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositLiquidity<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"lp_mint", market_config.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_collateral_account.owner == user.key(),
+        constraint = user_collateral_account.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_lp_account.owner == user.key(),
+        constraint = user_lp_account.mint == lp_mint.key() @ ForwardError::InvalidMint
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+
+    require!(amount > 0, ForwardError::InsufficientCollateral);
+
+    require!(
+        ctx.accounts.user_collateral_account.amount >= amount,
+        ForwardError::InsufficientCollateral
+    );
+
+    let LiquidityDeposit { shares, swept } = book_deposit(
+        &mut ctx.accounts.pool_state,
+        ctx.accounts.lp_mint.supply,
+        amount,
+    )?;
+
+    let market_config_key = ctx.accounts.market_config.key();
+    if swept > 0 {
+        let seeds = &[
+            b"collateral_vault",
+            market_config_key.as_ref(),
+            &[ctx.bumps.collateral_vault],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.fee_vault.to_account_info(),
+            authority: ctx.accounts.collateral_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, swept)?;
+    }
+
+    // Transfer collateral from LP to vault
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_collateral_account.to_account_info(),
+        to: ctx.accounts.collateral_vault.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    // Mint LP shares, signed by the pool state PDA
+    let seeds = &[
+        b"pool_state",
+        market_config_key.as_ref(),
+        &[ctx.accounts.pool_state.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: ctx.accounts.user_lp_account.to_account_info(),
+        authority: ctx.accounts.pool_state.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, shares)?;
+
+    let pool_state = &ctx.accounts.pool_state;
+    emit_cpi!(LiquidityDeposited {
+        market: market_config_key,
+        provider: ctx.accounts.user.key(),
+        amount,
+        shares,
+        lp_nav: pool_state.lp_nav,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Liquidity deposited: amount={}, shares={}, nav={}, swept={}",
+        amount,
+        shares,
+        pool_state.lp_nav,
+        swept
+    );
+
+    Ok(())
+}
+
+/// LP shares minted by a deposit and the ownerless NAV swept out before it
+pub(crate) struct LiquidityDeposit {
+    pub shares: u64,
+    pub swept: u64, // Moved from the collateral vault to the fee vault
+}
+
+/// Book a deposit of `amount` into a pool whose LP mint has `supply` shares
+///
+/// NAV left in a pool without shares (rounding dust, or premium booked
+/// after the last LP withdrew) belongs to no LP; it is swept to the fee vault
+/// so the first depositor doesn't take it. While some of it is still
+/// locked against open positions it can't move, so deposits wait.
+pub(crate) fn book_deposit(pool_state: &mut PoolState, supply: u64, amount: u64) -> Result<LiquidityDeposit> {
+    let swept = if supply == 0 { pool_state.lp_nav } else { 0 };
+    if swept > 0 {
+        require!(
+            pool_state.pool_collateral == 0,
+            ForwardError::InsufficientLiquidity
+        );
+        pool_state.lp_nav = 0;
+    }
+
+    let shares = pool_state.shares_for_deposit(amount, supply)?;
+    require!(shares > 0, ForwardError::InsufficientCollateral);
+
+    pool_state.lp_nav = pool_state
+        .lp_nav
+        .checked_add(amount)
+        .ok_or(ForwardError::MathOverflow)?;
+    Ok(LiquidityDeposit { shares, swept })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::withdraw_liquidity::book_withdrawal;
+    use crate::math;
+    use crate::state::{
+        CurveBreakpoint, CurveKind, CurveParams, Direction, Outcome, Position, PositionStatus,
+        RiskLimits,
+    };
+
+    const PRICE: u64 = 5000;
+
+    fn curve() -> CurveParams {
+        CurveParams {
+            alpha: 1000,
+            beta: 200,
+            max_exposure: 10_000_000,
+            min_price: 100,
+            max_price: 9900,
+            kind: CurveKind::Linear,
+            steepness: 0,
+            breakpoint_count: 0,
+            breakpoints: [CurveBreakpoint::default(); CurveParams::MAX_BREAKPOINTS],
+        }
+    }
+
+    fn risk_limits() -> RiskLimits {
+        RiskLimits {
+            max_total_exposure: 10_000_000,
+            max_long_share: 10_000,
+            max_short_share: 10_000,
+        }
+    }
+
+    /// A pool with its LP mint supply and vault balances, moved the way the
+    /// liquidity and trade handlers move them
+    struct Pool {
+        state: PoolState,
+        supply: u64,
+        collateral_vault: u64,
+        fee_vault: u64,
+    }
+
+    impl Pool {
+        fn new() -> Self {
+            Self {
+                state: PoolState {
+                    market: Pubkey::default(),
+                    total_long_exposure: 0,
+                    total_short_exposure: 0,
+                    pool_collateral: 0,
+                    position_counter: 0,
+                    lp_nav: 0,
+                    bump: 0,
+                },
+                supply: 0,
+                collateral_vault: 0,
+                fee_vault: 0,
+            }
+        }
+
+        fn deposit(&mut self, amount: u64) -> Result<u64> {
+            let LiquidityDeposit { shares, swept } = book_deposit(&mut self.state, self.supply, amount)?;
+            self.collateral_vault = self.collateral_vault - swept + amount;
+            self.fee_vault += swept;
+            self.supply += shares;
+            Ok(shares)
+        }
+
+        fn withdraw(&mut self, shares: u64) -> Result<u64> {
+            let amount = book_withdrawal(&mut self.state, self.supply, shares)?;
+            self.supply -= shares;
+            self.collateral_vault -= amount;
+            Ok(amount)
+        }
+
+        fn quote_long(&self, size: u64) -> crate::state::Quote {
+            math::quote_open(PRICE, &self.state, &curve(), &risk_limits(), 0, Direction::Long, size).unwrap()
+        }
+
+        /// Open a long of `size`, as `open_position` books it
+        fn open_long(&mut self, size: u64) -> Position {
+            let quote = self.quote_long(size);
+            self.state
+                .open(Direction::Long, size, quote.pool_collateral, quote.premium)
+                .unwrap();
+            self.collateral_vault = (self.collateral_vault as i64 + quote.user_collateral as i64 + quote.premium) as u64;
+            self.state.check_solvency(self.collateral_vault).unwrap();
+            Position {
+                owner: Pubkey::default(),
+                market: Pubkey::default(),
+                direction: Direction::Long,
+                size,
+                forward_price: quote.forward_price,
+                collateral_locked: quote.user_collateral,
+                premium_paid: quote.premium,
+                fee_paid: 0,
+                status: PositionStatus::Open,
+                bump: 0,
+            }
+        }
+
+        /// Close `position` at the oracle price, as `close_position` books it
+        fn close(&mut self, position: &Position) {
+            let quote = math::quote_close(PRICE, &self.state, &curve(), position, 0, position.size).unwrap();
+            self.state
+                .unwind(
+                    position.direction,
+                    position.size,
+                    quote.pool_collateral,
+                    quote.released_collateral,
+                    quote.payout,
+                )
+                .unwrap();
+            self.collateral_vault -= quote.payout;
+        }
+
+        /// Settle `position` at `outcome`, as `settle_position` books it
+        fn settle(&mut self, position: &Position, outcome: Outcome) {
+            let payout = math::calculate_settlement_payout(position.size, position.direction, outcome);
+            self.state.settle(position, payout).unwrap();
+            self.collateral_vault -= payout;
+        }
+    }
+
+    #[test]
+    fn deposit_trade_withdraw_keeps_locked_capital_in_the_pool() {
+        let mut pool = Pool::new();
+        let first = pool.deposit(1_000_000).unwrap();
+        assert_eq!(first, 1_000_000);
+
+        let position = pool.open_long(1_500_000);
+        assert_eq!(pool.collateral_vault, pool.state.lp_nav + position.collateral_locked);
+
+        // A later LP buys in at the NAV per share, premium included
+        let nav = pool.state.lp_nav;
+        let second = pool.deposit(500_000).unwrap();
+        assert_eq!(second, (500_000u128 * first as u128 / nav as u128) as u64);
+
+        // The first LP's stake is worth more than the unlocked capital
+        let stake = (first as u128 * pool.state.lp_nav as u128 / pool.supply as u128) as u64;
+        assert!(stake > pool.state.free_liquidity());
+        let before = (pool.state.lp_nav, pool.supply);
+        assert_eq!(
+            pool.withdraw(first).unwrap_err(),
+            ForwardError::InsufficientLiquidity.into()
+        );
+        assert_eq!((pool.state.lp_nav, pool.supply), before);
+
+        // Only the free part can leave
+        let free = pool.state.free_liquidity();
+        let partial = (free as u128 * pool.supply as u128 / pool.state.lp_nav as u128) as u64;
+        let withdrawn = pool.withdraw(partial).unwrap();
+        assert!(withdrawn <= free);
+        assert_eq!(pool.state.free_liquidity(), free - withdrawn);
+
+        // Once the position closes everyone can leave, and the vault empties
+        pool.close(&position);
+        assert_eq!(pool.state.pool_collateral, 0);
+        assert_eq!(pool.collateral_vault, pool.state.lp_nav);
+        pool.withdraw(second).unwrap();
+        pool.withdraw(first - partial).unwrap();
+        assert_eq!((pool.supply, pool.state.lp_nav, pool.collateral_vault), (0, 0, 0));
+    }
+
+    #[test]
+    fn first_deposit_sweeps_ownerless_nav_to_the_fee_vault() {
+        // NAV left behind without shares, partly still locked against a position
+        let mut pool = Pool::new();
+        pool.state.lp_nav = 250;
+        pool.state.pool_collateral = 100;
+        pool.collateral_vault = 250;
+
+        assert_eq!(
+            pool.deposit(1_000).unwrap_err(),
+            ForwardError::InsufficientLiquidity.into()
+        );
+        assert_eq!((pool.state.lp_nav, pool.collateral_vault, pool.fee_vault), (250, 250, 0));
+
+        // Once it is unlocked the deposit sweeps it and mints 1:1
+        pool.state.pool_collateral = 0;
+        assert_eq!(pool.deposit(1_000).unwrap(), 1_000);
+        assert_eq!((pool.state.lp_nav, pool.collateral_vault, pool.fee_vault), (1_000, 1_000, 250));
+        assert_eq!(pool.withdraw(1_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn deposit_after_wipe_out_reprices_the_old_shares() {
+        // Fund the pool with exactly the collateral a long locks, then resolve
+        // YES so the payout takes every LP's capital
+        let mut pool = Pool::new();
+        let quote = pool.quote_long(1_000_000);
+        let old_shares = pool
+            .deposit((quote.pool_collateral as i64 - quote.premium) as u64)
+            .unwrap();
+        let position = pool.open_long(1_000_000);
+        pool.settle(&position, Outcome::Yes);
+        assert_eq!((pool.state.lp_nav, pool.collateral_vault), (0, 0));
+        assert_eq!(pool.supply, old_shares);
+
+        // Fewer than WIPED_OUT_SHARES_PER_UNIT old shares: the NAV floor is one unit
+        assert!(old_shares < PoolState::WIPED_OUT_SHARES_PER_UNIT);
+        let amount = 1_000_000;
+        let new_shares = pool.deposit(amount).unwrap();
+        assert_eq!(new_shares, amount * old_shares);
+
+        // The old shares keep at most that unit of the new deposit
+        let old_value = pool.withdraw(old_shares).unwrap();
+        assert!(old_value <= 1);
+        assert_eq!(pool.withdraw(new_shares).unwrap(), amount - old_value);
+        assert_eq!((pool.supply, pool.state.lp_nav, pool.collateral_vault), (0, 0, 0));
+    }
+}
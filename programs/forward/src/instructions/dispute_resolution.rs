@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::oracle::ResolutionStatus;
+use crate::errors::ForwardError;
+use crate::events::ResolutionDisputed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DisputeResolution<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"resolution_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub resolution_oracle: Account<'info, crate::oracle::ResolutionOracle>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = disputer_collateral_account.owner == disputer.key(),
+        constraint = disputer_collateral_account.mint == bond_vault.mint @ ForwardError::InvalidMint
+    )]
+    pub disputer_collateral_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<DisputeResolution>) -> Result<()> {
+    require!(
+        ctx.accounts.resolution_oracle.status == ResolutionStatus::Proposed,
+        ForwardError::InvalidResolutionState
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < ctx.accounts.resolution_oracle.dispute_deadline,
+        ForwardError::DisputeWindowClosed
+    );
+
+    // Escrow a bond matching the proposer's
+    let bond = ctx.accounts.resolution_oracle.bond;
+    if bond > 0 {
+        require!(
+            ctx.accounts.disputer_collateral_account.amount >= bond,
+            ForwardError::InsufficientCollateral
+        );
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.disputer_collateral_account.to_account_info(),
+            to: ctx.accounts.bond_vault.to_account_info(),
+            authority: ctx.accounts.disputer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, bond)?;
+    }
+
+    let resolution_oracle = &mut ctx.accounts.resolution_oracle;
+    resolution_oracle.status = ResolutionStatus::Disputed;
+    resolution_oracle.disputer = ctx.accounts.disputer.key();
+
+    emit_cpi!(ResolutionDisputed {
+        market: ctx.accounts.market_config.key(),
+        disputer: resolution_oracle.disputer,
+        proposed_payout_fraction: resolution_oracle.proposed_payout_fraction,
+        bond,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Resolution disputed: proposed fraction={}, bond={}",
+        resolution_oracle.proposed_payout_fraction,
+        bond
+    );
+
+    Ok(())
+}
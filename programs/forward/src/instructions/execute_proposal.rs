@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::{Multisig, Proposal};
+use crate::errors::ForwardError;
+use crate::events::ProposalExecuted;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        seeds = [b"multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    #[account(
+        mut,
+        has_one = multisig,
+        constraint = !proposal.executed @ ForwardError::ProposalAlreadyExecuted,
+        constraint = proposal.config_version == multisig.config_version @ ForwardError::ProposalStale
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    /// CHECK: PDA that signs the proposed instruction; holds no data
+    #[account(
+        seeds = [b"multisig_signer", multisig.key().as_ref()],
+        bump = multisig.signer_bump
+    )]
+    pub multisig_signer: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>) -> Result<()> {
+    let approvals = ctx
+        .accounts
+        .proposal
+        .approvals
+        .iter()
+        .filter(|approved| **approved)
+        .count();
+    require!(
+        approvals >= ctx.accounts.multisig.threshold as usize,
+        ForwardError::NotEnoughApprovals
+    );
+    
+    // A proposal executing another would let one approval round sign twice
+    require!(
+        !ctx
+            .accounts
+            .proposal
+            .data
+            .starts_with(crate::instruction::ExecuteProposal::DISCRIMINATOR),
+        ForwardError::InvalidProposalInstruction
+    );
+    
+    // Mark executed and persist it before invoking, so the proposal cannot be
+    // replayed from within the proposed instruction
+    ctx.accounts.proposal.executed = true;
+    ctx.accounts.proposal.exit(&crate::ID)?;
+    
+    // Only instructions of this program can be proposed
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: ctx
+            .accounts
+            .proposal
+            .accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.pubkey,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: ctx.accounts.proposal.data.clone(),
+    };
+    
+    let multisig_key = ctx.accounts.multisig.key();
+    let seeds = &[
+        b"multisig_signer",
+        multisig_key.as_ref(),
+        &[ctx.accounts.multisig.signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    invoke_signed(&instruction, ctx.remaining_accounts, signer)?;
+    
+    emit_cpi!(ProposalExecuted {
+        proposal: ctx.accounts.proposal.key(),
+        approvals: approvals as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Proposal executed with {} approvals", approvals);
+    
+    Ok(())
+}
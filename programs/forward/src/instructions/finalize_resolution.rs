@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{MarketStatus, Outcome};
+use crate::oracle::ResolutionStatus;
+use crate::errors::ForwardError;
+use crate::events::MarketResolved;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeResolution<'info> {
+    #[account(
+        mut,
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"resolution_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub resolution_oracle: Account<'info, crate::oracle::ResolutionOracle>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = proposer_collateral_account.owner == resolution_oracle.proposer @ ForwardError::Unauthorized,
+        constraint = proposer_collateral_account.mint == bond_vault.mint @ ForwardError::InvalidMint
+    )]
+    pub proposer_collateral_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FinalizeResolution>) -> Result<()> {
+    require!(
+        ctx.accounts.resolution_oracle.status == ResolutionStatus::Proposed,
+        ForwardError::InvalidResolutionState
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= ctx.accounts.resolution_oracle.dispute_deadline,
+        ForwardError::DisputeWindowOpen
+    );
+
+    // Undisputed: return the proposer's bond
+    let bond = ctx.accounts.resolution_oracle.bond;
+    if bond > 0 {
+        let market_config_key = ctx.accounts.market_config.key();
+        let seeds = &[
+            b"bond_vault",
+            market_config_key.as_ref(),
+            &[ctx.bumps.bond_vault],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.bond_vault.to_account_info(),
+            to: ctx.accounts.proposer_collateral_account.to_account_info(),
+            authority: ctx.accounts.bond_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, bond)?;
+    }
+
+    // The proposed outcome becomes final
+    let resolution_oracle = &mut ctx.accounts.resolution_oracle;
+    resolution_oracle.payout_fraction = Some(resolution_oracle.proposed_payout_fraction);
+    resolution_oracle.resolved_at = Some(clock.unix_timestamp);
+    resolution_oracle.status = ResolutionStatus::Resolved;
+
+    let payout_fraction = resolution_oracle.proposed_payout_fraction;
+
+    ctx.accounts.market_config.status = MarketStatus::Resolved;
+
+    emit_cpi!(MarketResolved {
+        market: ctx.accounts.market_config.key(),
+        payout_fraction,
+        disputed: false,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Market resolved with outcome: {:?}",
+        Outcome::from_payout_fraction(payout_fraction)
+    );
+
+    Ok(())
+}
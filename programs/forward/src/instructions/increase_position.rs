@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{check_deadline, FeeLedger, MarketStatus, Position, PositionStatus, Quote, Referrer};
+use super::open_position::{credit_referrer, transfer_trade_payment};
+use crate::math;
+use crate::errors::ForwardError;
+use crate::oracle;
+use crate::events::PositionIncreased;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct IncreasePosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+    
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+    
+    #[account(
+        seeds = [b"price_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub price_oracle: Account<'info, crate::oracle::PriceOracle>,
+    
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = user_collateral_account.owner == user.key(),
+        constraint = user_collateral_account.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = position.owner == user.key(),
+        constraint = position.market == market_config.key(),
+        constraint = position.status == PositionStatus::Open @ ForwardError::PositionAlreadySettled
+    )]
+    pub position: Account<'info, Position>,
+    
+    /// Front-end or integrator credited with a share of the trading fee
+    #[account(
+        mut,
+        seeds = [b"referrer", referrer.authority.as_ref()],
+        bump = referrer.bump
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+    
+    /// Required with `referrer` to record the fees owed to it
+    #[account(
+        mut,
+        seeds = [b"fee_ledger"],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Option<Account<'info, FeeLedger>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<IncreasePosition>,
+    size: u64,
+    slippage_tolerance: Option<u64>,
+    limit_forward_price: Option<u64>,
+    max_premium: Option<i64>,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+    
+    require!(size > 0, ForwardError::PositionSizeExceedsLimit);
+    
+    // The added size always goes in the position's existing direction
+    let direction = ctx.accounts.position.direction;
+    
+    let curve_params = ctx
+        .accounts
+        .market_config
+        .active_curve_params(&ctx.accounts.global_config, now);
+    
+    // Read Polymarket price from oracle
+    let polymarket_price = oracle::read_price(&ctx.accounts.price_oracle)?;
+    require!(polymarket_price > 0, ForwardError::InvalidOracleData);
+    
+    // Price the trade: K, premium and collateral averaged over the exposure
+    // path it walks (same math as quote_open)
+    let quote = math::quote_open(
+        polymarket_price,
+        &ctx.accounts.pool_state,
+        &curve_params,
+        &ctx.accounts.market_config.risk_limits,
+        ctx.accounts.market_config.effective_fee_bps(&ctx.accounts.global_config),
+        direction,
+        size,
+    )?;
+    let Quote {
+        forward_price,
+        premium,
+        user_collateral,
+        fee,
+        ..
+    } = quote;
+    
+    // Check the user's slippage, limit price, premium cap and max size
+    quote.check_limits(
+        polymarket_price,
+        direction,
+        size,
+        slippage_tolerance,
+        limit_forward_price,
+        max_premium,
+    )?;
+    
+    // Collect collateral, premium and fee from the user
+    let market_config_key = ctx.accounts.market_config.key();
+    transfer_trade_payment(
+        &ctx.accounts.token_program,
+        &ctx.accounts.user,
+        &ctx.accounts.user_collateral_account,
+        &ctx.accounts.collateral_vault,
+        &[b"collateral_vault", market_config_key.as_ref(), &[ctx.bumps.collateral_vault]],
+        &ctx.accounts.fee_vault,
+        &quote,
+    )?;
+    
+    // Credit the referrer's share of the fee
+    if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+        credit_referrer(
+            referrer,
+            ctx.accounts.fee_ledger.as_mut(),
+            &ctx.accounts.global_config,
+            ctx.accounts.user.key(),
+            quote.fee,
+        )?;
+    }
+    
+    // Update pool state and check the vault still covers every position
+    let pool_state = &mut ctx.accounts.pool_state;
+    pool_state.open(direction, size, quote.pool_collateral, quote.premium)?;
+    ctx.accounts.collateral_vault.reload()?;
+    pool_state.check_solvency(ctx.accounts.collateral_vault.amount)?;
+    
+    // Fold the new clip into the position at a size-weighted average forward price
+    let position = &mut ctx.accounts.position;
+    let new_size = position
+        .size
+        .checked_add(size)
+        .ok_or(ForwardError::MathOverflow)?;
+    position.forward_price = ((position.forward_price as u128 * position.size as u128
+        + forward_price as u128 * size as u128)
+        / new_size as u128) as u64;
+    position.size = new_size;
+    position.collateral_locked = position
+        .collateral_locked
+        .checked_add(user_collateral)
+        .ok_or(ForwardError::MathOverflow)?;
+    position.premium_paid = position
+        .premium_paid
+        .checked_add(premium)
+        .ok_or(ForwardError::MathOverflow)?;
+    position.fee_paid = position
+        .fee_paid
+        .checked_add(fee)
+        .ok_or(ForwardError::MathOverflow)?;
+    
+    emit_cpi!(PositionIncreased {
+        position: position.key(),
+        owner: position.owner,
+        market: position.market,
+        direction,
+        size,
+        forward_price,
+        premium,
+        collateral: user_collateral,
+        fee,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.key()),
+        new_size,
+        new_forward_price: position.forward_price,
+        oracle_price: polymarket_price,
+        oracle_timestamp: ctx.accounts.price_oracle.timestamp,
+        total_long_exposure: ctx.accounts.pool_state.total_long_exposure,
+        total_short_exposure: ctx.accounts.pool_state.total_short_exposure,
+        timestamp: now,
+    });
+    
+    let direction_str = match direction {
+        crate::state::Direction::Long => "Long",
+        crate::state::Direction::Short => "Short",
+    };
+    
+    msg!(
+        "Position increased: {} {} at K={}, premium={}, collateral={}, fee={}, new size={} at avg K={}",
+        direction_str,
+        size,
+        forward_price,
+        premium,
+        user_collateral,
+        fee,
+        position.size,
+        position.forward_price
+    );
+    
+    Ok(())
+}
+
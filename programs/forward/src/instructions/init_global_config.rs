@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::state::{GlobalConfig, CurveParams};
-use crate::math::BASIS_POINTS;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{GlobalConfig, CurveParams, FeeLedger, ResolutionParams};
 use crate::errors::ForwardError;
+use crate::events::GlobalConfigInitialized;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitGlobalConfig<'info> {
     #[account(mut)]
@@ -17,37 +19,84 @@ pub struct InitGlobalConfig<'info> {
     )]
     pub global_config: Account<'info, GlobalConfig>,
     
-    /// CHECK: Collateral mint account (e.g., USDC)
-    pub collateral_mint: AccountInfo<'info>,
+    /// Collateral mint (e.g., USDC)
+    pub collateral_mint: Account<'info, Mint>,
     
+    /// Protocol fees accrue here, never in a market's collateral vault
+    #[account(
+        init,
+        payer = admin,
+        token::mint = collateral_mint,
+        token::authority = fee_vault,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = FeeLedger::LEN,
+        seeds = [b"fee_ledger"],
+        bump
+    )]
+    pub fee_ledger: Account<'info, FeeLedger>,
+    
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<InitGlobalConfig>,
     curve_params: CurveParams,
+    curve_update_delay: i64,
+    resolution_params: ResolutionParams,
+    fee_bps: u64,
 ) -> Result<()> {
-    // Validate curve parameters against ARCHITECTURE.md:
-    // 0 <= p_min <= p_max <= 1 (in basis points) and non‑degenerate exposure.
+    curve_params.validate()?;
     require!(
-        curve_params.min_price <= curve_params.max_price,
-        ForwardError::InvalidOracleData
+        (0..=GlobalConfig::MAX_CURVE_UPDATE_DELAY).contains(&curve_update_delay),
+        ForwardError::InvalidCurveUpdateDelay
     );
+    resolution_params.validate()?;
     require!(
-        curve_params.max_price <= BASIS_POINTS,
-        ForwardError::InvalidOracleData
-    );
-    require!(
-        curve_params.max_exposure > 0,
-        ForwardError::InvalidOracleData
+        fee_bps <= GlobalConfig::MAX_FEE_BPS,
+        ForwardError::FeeTooHigh
     );
     let global_config = &mut ctx.accounts.global_config;
     
     global_config.admin = ctx.accounts.admin.key();
+    global_config.pending_admin = None;
+    // All roles start with the admin until delegated via set_role
+    global_config.price_keeper = ctx.accounts.admin.key();
+    global_config.resolution_keeper = ctx.accounts.admin.key();
+    global_config.risk_manager = ctx.accounts.admin.key();
     global_config.collateral_mint = ctx.accounts.collateral_mint.key();
     global_config.curve_params = curve_params;
+    global_config.pending_curve_params = None;
+    global_config.curve_update_delay = curve_update_delay;
+    global_config.pending_curve_update_delay = None;
+    global_config.resolution_params = resolution_params;
+    global_config.fee_bps = fee_bps;
+    // Referrals earn nothing until the admin configures tiers
+    global_config.referral_tiers = [0; GlobalConfig::MAX_REFERRAL_TIERS];
+    global_config.crank_fee = 0;
     global_config.bump = ctx.bumps.global_config;
     
+    let fee_ledger = &mut ctx.accounts.fee_ledger;
+    fee_ledger.referral_fees_owed = 0;
+    fee_ledger.bump = ctx.bumps.fee_ledger;
+    
+    emit_cpi!(GlobalConfigInitialized {
+        admin: global_config.admin,
+        collateral_mint: global_config.collateral_mint,
+        curve_params: global_config.curve_params.clone(),
+        curve_update_delay,
+        resolution_params: global_config.resolution_params.clone(),
+        fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     msg!("Global config initialized with admin: {}", global_config.admin);
     
     Ok(())
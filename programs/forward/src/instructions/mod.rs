@@ -1,11 +1,48 @@
 pub mod init_global_config;
 pub mod create_market;
 pub mod update_curve_params;
+pub mod cancel_curve_params_update;
+pub mod cancel_market_curve_params_update;
+pub mod update_curve_update_delay;
+pub mod update_market_curve_params;
+pub mod set_role;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod renounce_admin;
+pub mod create_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod update_multisig;
 pub mod close_market_for_trading;
+pub mod cancel_market;
+pub mod close_market;
 pub mod open_position;
+pub mod increase_position;
 pub mod settle_position;
+pub mod refund_position;
+pub mod close_settled_position;
+pub mod crank_settle;
+pub mod close_position;
+pub mod reduce_position;
+pub mod quote_open;
+pub mod quote_close;
 pub mod update_market_resolution;
+pub mod update_resolution_params;
+pub mod update_fee_bps;
+pub mod update_market_fee_bps;
+pub mod withdraw_fees;
+pub mod update_crank_fee;
+pub mod register_referrer;
+pub mod set_referral_tier;
+pub mod set_referrer_tier;
+pub mod claim_referral_fees;
+pub mod propose_resolution;
+pub mod dispute_resolution;
+pub mod finalize_resolution;
 pub mod update_price_oracle;
+pub mod deposit_liquidity;
+pub mod withdraw_liquidity;
 
 // Suppress ambiguous glob re-exports warning - each module has a handler function
 // but they're only used via explicit paths in lib.rs, so the ambiguity is harmless
@@ -16,13 +53,86 @@ pub use create_market::*;
 #[allow(ambiguous_glob_reexports)]
 pub use update_curve_params::*;
 #[allow(ambiguous_glob_reexports)]
+pub use cancel_curve_params_update::*;
+#[allow(ambiguous_glob_reexports)]
+pub use cancel_market_curve_params_update::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_curve_update_delay::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_market_curve_params::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_role::*;
+#[allow(ambiguous_glob_reexports)]
+pub use propose_admin::*;
+#[allow(ambiguous_glob_reexports)]
+pub use accept_admin::*;
+#[allow(ambiguous_glob_reexports)]
+pub use renounce_admin::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_multisig::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_proposal::*;
+#[allow(ambiguous_glob_reexports)]
+pub use approve_proposal::*;
+#[allow(ambiguous_glob_reexports)]
+pub use execute_proposal::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_multisig::*;
+#[allow(ambiguous_glob_reexports)]
 pub use close_market_for_trading::*;
 #[allow(ambiguous_glob_reexports)]
+pub use cancel_market::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_market::*;
+#[allow(ambiguous_glob_reexports)]
 pub use open_position::*;
 #[allow(ambiguous_glob_reexports)]
+pub use increase_position::*;
+#[allow(ambiguous_glob_reexports)]
 pub use settle_position::*;
 #[allow(ambiguous_glob_reexports)]
+pub use refund_position::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_settled_position::*;
+#[allow(ambiguous_glob_reexports)]
+pub use crank_settle::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_position::*;
+#[allow(ambiguous_glob_reexports)]
+pub use reduce_position::*;
+#[allow(ambiguous_glob_reexports)]
+pub use quote_open::*;
+#[allow(ambiguous_glob_reexports)]
+pub use quote_close::*;
+#[allow(ambiguous_glob_reexports)]
 pub use update_market_resolution::*;
 #[allow(ambiguous_glob_reexports)]
+pub use update_resolution_params::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_fee_bps::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_market_fee_bps::*;
+#[allow(ambiguous_glob_reexports)]
+pub use withdraw_fees::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_crank_fee::*;
+#[allow(ambiguous_glob_reexports)]
+pub use register_referrer::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_referral_tier::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_referrer_tier::*;
+#[allow(ambiguous_glob_reexports)]
+pub use claim_referral_fees::*;
+#[allow(ambiguous_glob_reexports)]
+pub use propose_resolution::*;
+#[allow(ambiguous_glob_reexports)]
+pub use dispute_resolution::*;
+#[allow(ambiguous_glob_reexports)]
+pub use finalize_resolution::*;
+#[allow(ambiguous_glob_reexports)]
 pub use update_price_oracle::*;
-
+#[allow(ambiguous_glob_reexports)]
+pub use deposit_liquidity::*;
+#[allow(ambiguous_glob_reexports)]
+pub use withdraw_liquidity::*;
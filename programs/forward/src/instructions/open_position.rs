@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{Direction, MarketStatus, Position, PositionStatus};
-use crate::math::{self, BASIS_POINTS};
+use crate::state::{check_deadline, Direction, FeeLedger, GlobalConfig, MarketStatus, Position, PositionStatus, Quote, Referrer};
+use crate::math;
 use crate::errors::ForwardError;
 use crate::oracle;
+use crate::events::PositionOpened;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct OpenPosition<'info> {
     #[account(mut)]
@@ -17,7 +19,6 @@ pub struct OpenPosition<'info> {
     pub global_config: Account<'info, crate::state::GlobalConfig>,
     
     #[account(
-        mut,
         seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
         bump = market_config.bump
     )]
@@ -31,7 +32,6 @@ pub struct OpenPosition<'info> {
     pub pool_state: Account<'info, crate::state::PoolState>,
     
     #[account(
-        mut,
         seeds = [b"price_oracle", market_config.key().as_ref()],
         bump
     )]
@@ -44,6 +44,13 @@ pub struct OpenPosition<'info> {
     )]
     pub collateral_vault: Account<'info, TokenAccount>,
     
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
     #[account(
         mut,
         constraint = user_collateral_account.owner == user.key(),
@@ -60,6 +67,22 @@ pub struct OpenPosition<'info> {
     )]
     pub position: Account<'info, Position>,
     
+    /// Front-end or integrator credited with a share of the trading fee
+    #[account(
+        mut,
+        seeds = [b"referrer", referrer.authority.as_ref()],
+        bump = referrer.bump
+    )]
+    pub referrer: Option<Account<'info, Referrer>>,
+    
+    /// Required with `referrer` to record the fees owed to it
+    #[account(
+        mut,
+        seeds = [b"fee_ledger"],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Option<Account<'info, FeeLedger>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -69,152 +92,87 @@ pub fn handler(
     direction: Direction,
     size: u64,
     slippage_tolerance: Option<u64>,
+    limit_forward_price: Option<u64>,
+    max_premium: Option<i64>,
+    expires_at: Option<i64>,
 ) -> Result<()> {
     require!(
         ctx.accounts.market_config.status == MarketStatus::Active,
         ForwardError::MarketNotActive
     );
     
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+    
     require!(size > 0, ForwardError::PositionSizeExceedsLimit);
     
+    let curve_params = ctx
+        .accounts
+        .market_config
+        .active_curve_params(&ctx.accounts.global_config, now);
+    
     // Read Polymarket price from oracle
     let polymarket_price = oracle::read_price(&ctx.accounts.price_oracle)?;
     require!(polymarket_price > 0, ForwardError::InvalidOracleData);
     
-    // Calculate forward price K using pAMM curve
-    let forward_price = math::calculate_forward_price(
+    // Price the trade: K, premium and collateral averaged over the exposure
+    // path it walks (same math as quote_open)
+    let quote = math::quote_open(
         polymarket_price,
         &ctx.accounts.pool_state,
-        &ctx.accounts.global_config.curve_params,
-    );
-    
-    // Check slippage if provided
-    if let Some(slippage) = slippage_tolerance {
-        require!(
-            slippage <= BASIS_POINTS,
-            ForwardError::InvalidOracleData
-        );
-        
-        let price_diff = if forward_price > polymarket_price {
-            forward_price - polymarket_price
-        } else {
-            polymarket_price - forward_price
-        };
-        let slippage_bps = (price_diff * BASIS_POINTS) / polymarket_price;
-        require!(
-            slippage_bps <= slippage,
-            ForwardError::SlippageExceeded
-        );
-    }
-    
-    // Calculate premium
-    let premium_rate = math::calculate_premium_rate(
-        &ctx.accounts.pool_state,
-        &ctx.accounts.global_config.curve_params,
+        &curve_params,
+        &ctx.accounts.market_config.risk_limits,
+        ctx.accounts.market_config.effective_fee_bps(&ctx.accounts.global_config),
         direction,
-    );
-    let premium = math::calculate_premium(premium_rate, size);
-    
-    // Calculate required collateral
-    let user_collateral = math::calculate_collateral(
-        forward_price,
         size,
-        direction,
-    );
-    
-    // Calculate pool collateral (opposite side)
-    let pool_collateral = math::calculate_collateral(
+    )?;
+    let Quote {
         forward_price,
-        size,
-        match direction {
-            Direction::Long => Direction::Short,
-            Direction::Short => Direction::Long,
-        },
-    );
+        premium,
+        user_collateral,
+        fee,
+        ..
+    } = quote;
     
-    // Check max size
-    let max_size = math::calculate_max_size(
-        &ctx.accounts.pool_state,
-        &ctx.accounts.global_config.curve_params,
-        &ctx.accounts.market_config.risk_limits,
+    // Check the user's slippage, limit price, premium cap and max size
+    quote.check_limits(
+        polymarket_price,
         direction,
-    );
-    
-    require!(
-        size <= max_size,
-        ForwardError::PositionSizeExceedsLimit
-    );
-    
-    // Calculate total amount user needs to transfer
-    // User collateral + premium (if positive) or -premium (if negative, user receives)
-    let user_transfer_amount = if premium > 0 {
-        user_collateral
-            .checked_add(premium as u64)
-            .ok_or(ForwardError::MathOverflow)?
-    } else {
-        // Premium is negative, user receives it, but still needs to lock collateral
-        user_collateral
-    };
-    
-    // Check user has enough balance
-    require!(
-        ctx.accounts.user_collateral_account.amount >= user_transfer_amount,
-        ForwardError::InsufficientCollateral
-    );
+        size,
+        slippage_tolerance,
+        limit_forward_price,
+        max_premium,
+    )?;
     
-    // Transfer user collateral + premium to vault
-    if user_transfer_amount > 0 {
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.user_collateral_account.to_account_info(),
-            to: ctx.accounts.collateral_vault.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, user_transfer_amount)?;
-    }
+    // Collect collateral, premium and fee from the user
+    let market_config_key = ctx.accounts.market_config.key();
+    transfer_trade_payment(
+        &ctx.accounts.token_program,
+        &ctx.accounts.user,
+        &ctx.accounts.user_collateral_account,
+        &ctx.accounts.collateral_vault,
+        &[b"collateral_vault", market_config_key.as_ref(), &[ctx.bumps.collateral_vault]],
+        &ctx.accounts.fee_vault,
+        &quote,
+    )?;
     
-    // If premium is negative, transfer it back to user (user receives premium)
-    if premium < 0 {
-        let premium_amount = premium.unsigned_abs();
-        let market_config_key = ctx.accounts.market_config.key();
-        let seeds = &[
-            b"collateral_vault",
-            market_config_key.as_ref(),
-            &[ctx.bumps.collateral_vault],
-        ];
-        let signer = &[&seeds[..]];
-        
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.collateral_vault.to_account_info(),
-            to: ctx.accounts.user_collateral_account.to_account_info(),
-            authority: ctx.accounts.collateral_vault.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, premium_amount)?;
+    // Credit the referrer's share of the fee
+    if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+        credit_referrer(
+            referrer,
+            ctx.accounts.fee_ledger.as_mut(),
+            &ctx.accounts.global_config,
+            ctx.accounts.user.key(),
+            quote.fee,
+        )?;
     }
     
-    // Update pool state
+    // Update pool state and check the vault still covers every position
     let pool_state = &mut ctx.accounts.pool_state;
-    match direction {
-        Direction::Long => {
-            pool_state.total_long_exposure = pool_state
-                .total_long_exposure
-                .checked_add(size)
-                .ok_or(ForwardError::MathOverflow)?;
-        }
-        Direction::Short => {
-            pool_state.total_short_exposure = pool_state
-                .total_short_exposure
-                .checked_add(size)
-                .ok_or(ForwardError::MathOverflow)?;
-        }
-    }
-    pool_state.pool_collateral = pool_state
-        .pool_collateral
-        .checked_add(pool_collateral)
-        .ok_or(ForwardError::MathOverflow)?;
+    pool_state.open(direction, size, quote.pool_collateral, quote.premium)?;
+    ctx.accounts.collateral_vault.reload()?;
+    pool_state.check_solvency(ctx.accounts.collateral_vault.amount)?;
+    let position_id = pool_state.position_counter;
     pool_state.position_counter = pool_state
         .position_counter
         .checked_add(1)
@@ -229,23 +187,252 @@ pub fn handler(
     position.forward_price = forward_price;
     position.collateral_locked = user_collateral;
     position.premium_paid = premium;
+    position.fee_paid = fee;
     position.status = PositionStatus::Open;
     position.bump = ctx.bumps.position;
     
+    emit_cpi!(PositionOpened {
+        position: position.key(),
+        owner: position.owner,
+        market: position.market,
+        position_id,
+        direction,
+        size,
+        forward_price,
+        premium,
+        collateral: user_collateral,
+        fee,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.key()),
+        oracle_price: polymarket_price,
+        oracle_timestamp: ctx.accounts.price_oracle.timestamp,
+        total_long_exposure: ctx.accounts.pool_state.total_long_exposure,
+        total_short_exposure: ctx.accounts.pool_state.total_short_exposure,
+        timestamp: now,
+    });
+    
     let direction_str = match direction {
         crate::state::Direction::Long => "Long",
         crate::state::Direction::Short => "Short",
     };
     
     msg!(
-        "Position opened: {} {} at K={}, premium={}, collateral={}",
+        "Position opened: {} {} at K={}, premium={}, collateral={}, fee={}",
         direction_str,
         size,
         forward_price,
         premium,
-        user_collateral
+        user_collateral,
+        fee
     );
     
     Ok(())
 }
 
+
+/// Token movements that collect a trade's payment
+///
+/// The user's collateral and a positive premium go into the market vault, a
+/// negative premium is paid back out of it, and the trading fee goes to the
+/// fee vault, outside the pool's accounting.
+pub(crate) struct TradePayment {
+    pub to_collateral_vault: u64,   // From the user
+    pub from_collateral_vault: u64, // To the user
+    pub to_fee_vault: u64,          // From the user
+}
+
+impl TradePayment {
+    pub fn new(quote: &Quote) -> Result<Self> {
+        // A negative premium is received but the collateral is still locked
+        let to_collateral_vault = if quote.premium > 0 {
+            quote
+                .user_collateral
+                .checked_add(quote.premium as u64)
+                .ok_or(ForwardError::MathOverflow)?
+        } else {
+            quote.user_collateral
+        };
+        Ok(Self {
+            to_collateral_vault,
+            from_collateral_vault: if quote.premium < 0 { quote.premium.unsigned_abs() } else { 0 },
+            to_fee_vault: quote.fee,
+        })
+    }
+}
+
+/// Collect a trade's payment from the user, as laid out by [`TradePayment`]
+pub(crate) fn transfer_trade_payment<'info>(
+    token_program: &Program<'info, Token>,
+    user: &Signer<'info>,
+    user_collateral_account: &Account<'info, TokenAccount>,
+    collateral_vault: &Account<'info, TokenAccount>,
+    collateral_vault_seeds: &[&[u8]],
+    fee_vault: &Account<'info, TokenAccount>,
+    quote: &Quote,
+) -> Result<()> {
+    let payment = TradePayment::new(quote)?;
+    
+    // Check user has enough balance, including the trading fee
+    require!(
+        user_collateral_account.amount
+            >= payment
+                .to_collateral_vault
+                .checked_add(payment.to_fee_vault)
+                .ok_or(ForwardError::MathOverflow)?,
+        ForwardError::InsufficientCollateral
+    );
+    
+    if payment.to_collateral_vault > 0 {
+        let cpi_accounts = Transfer {
+            from: user_collateral_account.to_account_info(),
+            to: collateral_vault.to_account_info(),
+            authority: user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, payment.to_collateral_vault)?;
+    }
+    
+    if payment.from_collateral_vault > 0 {
+        let signer = &[collateral_vault_seeds];
+        let cpi_accounts = Transfer {
+            from: collateral_vault.to_account_info(),
+            to: user_collateral_account.to_account_info(),
+            authority: collateral_vault.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, payment.from_collateral_vault)?;
+    }
+    
+    if payment.to_fee_vault > 0 {
+        let cpi_accounts = Transfer {
+            from: user_collateral_account.to_account_info(),
+            to: fee_vault.to_account_info(),
+            authority: user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, payment.to_fee_vault)?;
+    }
+    
+    Ok(())
+}
+
+/// Credit `referrer` its tier's share of `fee`
+///
+/// The share stays in the fee vault until claimed and is recorded in the fee
+/// ledger so `withdraw_fees` leaves it behind.
+pub(crate) fn credit_referrer(
+    referrer: &mut Referrer,
+    fee_ledger: Option<&mut Account<FeeLedger>>,
+    global_config: &GlobalConfig,
+    user: Pubkey,
+    fee: u64,
+) -> Result<()> {
+    require!(referrer.authority != user, ForwardError::SelfReferral);
+    let share = global_config.referral_share(referrer.tier, fee)?;
+    referrer.accrued = referrer
+        .accrued
+        .checked_add(share)
+        .ok_or(ForwardError::MathOverflow)?;
+    let fee_ledger = fee_ledger.ok_or(ForwardError::FeeLedgerRequired)?;
+    fee_ledger.referral_fees_owed = fee_ledger
+        .referral_fees_owed
+        .checked_add(share)
+        .ok_or(ForwardError::MathOverflow)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{CurveBreakpoint, CurveKind, CurveParams, PoolState, RiskLimits};
+
+    fn quote(fee_bps: u64) -> Quote {
+        let curve_params = CurveParams {
+            alpha: 1000,
+            beta: 200,
+            max_exposure: 10_000_000,
+            min_price: 100,
+            max_price: 9900,
+            kind: CurveKind::Linear,
+            steepness: 0,
+            breakpoint_count: 0,
+            breakpoints: [CurveBreakpoint::default(); CurveParams::MAX_BREAKPOINTS],
+        };
+        let risk_limits = RiskLimits {
+            max_total_exposure: 10_000_000,
+            max_long_share: 10_000,
+            max_short_share: 10_000,
+        };
+        math::quote_open(5000, &pool(), &curve_params, &risk_limits, fee_bps, Direction::Long, 1_000_000).unwrap()
+    }
+
+    fn pool() -> PoolState {
+        PoolState {
+            market: Pubkey::default(),
+            total_long_exposure: 0,
+            total_short_exposure: 0,
+            pool_collateral: 0,
+            position_counter: 0,
+            lp_nav: 1_000_000,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn fee_goes_to_the_fee_vault() {
+        let paid = |premium| {
+            let quote = Quote {
+                forward_price: 5000,
+                premium,
+                user_collateral: 500_000,
+                pool_collateral: 500_000,
+                max_size: 10_000_000,
+                fee: 1_500,
+            };
+            let payment = TradePayment::new(&quote).unwrap();
+            (payment.to_collateral_vault, payment.from_collateral_vault, payment.to_fee_vault)
+        };
+        assert_eq!(paid(2_000), (502_000, 0, 1_500));
+        assert_eq!(paid(0), (500_000, 0, 1_500));
+        // A negative premium is paid out of the market vault, never the fee vault
+        assert_eq!(paid(-2_000), (500_000, 2_000, 1_500));
+    }
+
+    #[test]
+    fn fee_is_outside_the_pool_accounting() {
+        let (free, charged) = (quote(0), quote(100));
+        assert!(charged.fee > 0);
+
+        let (free_payment, charged_payment) =
+            (TradePayment::new(&free).unwrap(), TradePayment::new(&charged).unwrap());
+        assert_eq!(free_payment.to_collateral_vault, charged_payment.to_collateral_vault);
+        assert_eq!(free_payment.from_collateral_vault, charged_payment.from_collateral_vault);
+        assert_eq!((free_payment.to_fee_vault, charged_payment.to_fee_vault), (0, charged.fee));
+
+        // LPs book the same premium and lock the same collateral either way
+        let (mut without_fee, mut with_fee) = (pool(), pool());
+        without_fee.open(Direction::Long, 1_000_000, free.pool_collateral, free.premium).unwrap();
+        with_fee.open(Direction::Long, 1_000_000, charged.pool_collateral, charged.premium).unwrap();
+        assert_eq!(without_fee.lp_nav, with_fee.lp_nav);
+        assert_eq!(without_fee.pool_collateral, with_fee.pool_collateral);
+    }
+
+    #[test]
+    fn fees_do_not_count_towards_solvency() {
+        let quote = quote(1000);
+        let payment = TradePayment::new(&quote).unwrap();
+        let mut pool_state = pool();
+        pool_state.open(Direction::Long, 1_000_000, quote.pool_collateral, quote.premium).unwrap();
+
+        // One unit short of the liability after the trade: the fee collected
+        // alongside it would cover the gap but sits in the fee vault
+        let liability = pool_state.max_liability();
+        let short_vault = liability - 1;
+        assert!(short_vault + payment.to_fee_vault >= liability);
+        assert_eq!(
+            pool_state.check_solvency(short_vault).unwrap_err(),
+            ForwardError::VaultInsolvent.into()
+        );
+        assert!(pool_state.check_solvency(liability).is_ok());
+    }
+}
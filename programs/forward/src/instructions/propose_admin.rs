@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::errors::ForwardError;
+use crate::events::AdminProposed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeAdmin>,
+    new_admin: Option<Pubkey>,
+) -> Result<()> {
+    // The transfer only takes effect once the new admin accepts; None cancels a pending proposal
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.pending_admin = new_admin;
+    
+    emit_cpi!(AdminProposed {
+        admin: global_config.admin,
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Pending admin set to {:?}", new_admin);
+    
+    Ok(())
+}
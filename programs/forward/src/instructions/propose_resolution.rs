@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{MarketStatus, Outcome};
+use crate::oracle::ResolutionStatus;
+use crate::errors::ForwardError;
+use crate::events::ResolutionProposed;
+use crate::math::BASIS_POINTS;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeResolution<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"resolution_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub resolution_oracle: Account<'info, crate::oracle::ResolutionOracle>,
+
+    #[account(
+        address = global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub collateral_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = proposer,
+        token::mint = collateral_mint,
+        token::authority = bond_vault,
+        seeds = [b"bond_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = proposer_collateral_account.owner == proposer.key(),
+        constraint = proposer_collateral_account.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub proposer_collateral_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeResolution>, outcome: Outcome) -> Result<()> {
+    let market_config = &mut ctx.accounts.market_config;
+
+    require!(
+        market_config.status == MarketStatus::Active || market_config.status == MarketStatus::TradingClosed,
+        ForwardError::InvalidMarketStatus
+    );
+
+    require!(
+        ctx.accounts.resolution_oracle.status == ResolutionStatus::Unproposed,
+        ForwardError::InvalidResolutionState
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= market_config.resolution_timestamp,
+        ForwardError::ResolutionTooEarly
+    );
+
+    // Split outcomes carry a YES payout fraction in basis points
+    require!(
+        outcome.payout_fraction() <= BASIS_POINTS,
+        ForwardError::InvalidOracleData
+    );
+
+    // Escrow the proposer's bond
+    let bond = ctx.accounts.global_config.resolution_params.bond_amount;
+    if bond > 0 {
+        require!(
+            ctx.accounts.proposer_collateral_account.amount >= bond,
+            ForwardError::InsufficientCollateral
+        );
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.proposer_collateral_account.to_account_info(),
+            to: ctx.accounts.bond_vault.to_account_info(),
+            authority: ctx.accounts.proposer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, bond)?;
+    }
+
+    let dispute_deadline = clock
+        .unix_timestamp
+        .checked_add(ctx.accounts.global_config.resolution_params.liveness_period)
+        .ok_or(ForwardError::MathOverflow)?;
+
+    let resolution_oracle = &mut ctx.accounts.resolution_oracle;
+    resolution_oracle.status = ResolutionStatus::Proposed;
+    resolution_oracle.proposer = ctx.accounts.proposer.key();
+    resolution_oracle.proposed_payout_fraction = outcome.payout_fraction();
+    resolution_oracle.dispute_deadline = dispute_deadline;
+    resolution_oracle.bond = bond;
+
+    // No more trading once an outcome is on the table
+    market_config.status = MarketStatus::TradingClosed;
+
+    emit_cpi!(ResolutionProposed {
+        market: market_config.key(),
+        proposer: ctx.accounts.proposer.key(),
+        payout_fraction: outcome.payout_fraction(),
+        bond,
+        dispute_deadline,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Resolution proposed: outcome={:?}, bond={}, dispute deadline={}",
+        outcome,
+        bond,
+        dispute_deadline
+    );
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{CloseQuote, MarketStatus, PositionStatus};
+use crate::math;
+use crate::errors::ForwardError;
+use crate::oracle;
+
+#[derive(Accounts)]
+pub struct QuoteClose<'info> {
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        seeds = [b"price_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub price_oracle: Account<'info, crate::oracle::PriceOracle>,
+
+    #[account(
+        constraint = position.market == market_config.key(),
+        constraint = position.status == PositionStatus::Open @ ForwardError::PositionAlreadySettled
+    )]
+    pub position: Account<'info, crate::state::Position>,
+}
+
+pub fn handler(ctx: Context<QuoteClose>, size: Option<u64>) -> Result<CloseQuote> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+
+    // Quote the whole position unless a partial reduction is requested
+    let size = size.unwrap_or(ctx.accounts.position.size);
+
+    // Price with the curve the exit would use, including queued parameters
+    // that close_position / reduce_position would activate
+    let now = Clock::get()?.unix_timestamp;
+    let curve_params = ctx
+        .accounts
+        .market_config
+        .active_curve_params(&ctx.accounts.global_config, now);
+
+    let polymarket_price = oracle::read_price(&ctx.accounts.price_oracle)?;
+    require!(polymarket_price > 0, ForwardError::InvalidOracleData);
+
+    let quote = math::quote_close(
+        polymarket_price,
+        &ctx.accounts.pool_state,
+        &curve_params,
+        &ctx.accounts.position,
+        ctx.accounts.market_config.effective_fee_bps(&ctx.accounts.global_config),
+        size,
+    )?;
+
+    msg!(
+        "Close quote: exit K={}, payout={}, fee={}, released collateral={}, pool collateral={}",
+        quote.exit_price,
+        quote.payout,
+        quote.fee,
+        quote.released_collateral,
+        quote.pool_collateral
+    );
+
+    Ok(quote)
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{Direction, MarketStatus, Quote};
+use crate::math;
+use crate::errors::ForwardError;
+use crate::oracle;
+
+#[derive(Accounts)]
+pub struct QuoteOpen<'info> {
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+    
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+    
+    #[account(
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+    
+    #[account(
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"price_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub price_oracle: Account<'info, crate::oracle::PriceOracle>,
+}
+
+pub fn handler(
+    ctx: Context<QuoteOpen>,
+    direction: Direction,
+    size: u64,
+) -> Result<Quote> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+    
+    require!(size > 0, ForwardError::PositionSizeExceedsLimit);
+    
+    // Price with the curve the trade would use, including queued parameters
+    // that open_position would activate
+    let now = Clock::get()?.unix_timestamp;
+    let curve_params = ctx
+        .accounts
+        .market_config
+        .active_curve_params(&ctx.accounts.global_config, now);
+    
+    let polymarket_price = oracle::read_price(&ctx.accounts.price_oracle)?;
+    require!(polymarket_price > 0, ForwardError::InvalidOracleData);
+    
+    let mut quote = math::quote_open(
+        polymarket_price,
+        &ctx.accounts.pool_state,
+        &curve_params,
+        &ctx.accounts.market_config.risk_limits,
+        ctx.accounts.market_config.effective_fee_bps(&ctx.accounts.global_config),
+        direction,
+        size,
+    )?;
+    
+    // Besides the risk limits, a trade must fit the free LP liquidity and
+    // keep the vault solvent, as open_position enforces
+    quote.max_size = math::calculate_fillable_size(
+        polymarket_price,
+        &ctx.accounts.pool_state,
+        &curve_params,
+        &ctx.accounts.market_config.risk_limits,
+        direction,
+        ctx.accounts.collateral_vault.amount,
+        quote.max_size,
+    );
+    
+    msg!(
+        "Quote: K={}, premium={}, user collateral={}, pool collateral={}, max size={}, fee={}",
+        quote.forward_price,
+        quote.premium,
+        quote.user_collateral,
+        quote.pool_collateral,
+        quote.max_size,
+        quote.fee
+    );
+    
+    Ok(quote)
+}
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{check_deadline, CloseQuote, MarketStatus, PositionStatus};
+use super::close_position::transfer_close_payout;
+use crate::math;
+use crate::errors::ForwardError;
+use crate::oracle;
+use crate::events::PositionClosed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReducePosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        seeds = [b"price_oracle", market_config.key().as_ref()],
+        bump
+    )]
+    pub price_oracle: Account<'info, crate::oracle::PriceOracle>,
+
+    #[account(
+        mut,
+        constraint = position.owner == user.key(),
+        constraint = position.market == market_config.key(),
+        constraint = position.status == PositionStatus::Open @ ForwardError::PositionAlreadySettled
+    )]
+    pub position: Account<'info, crate::state::Position>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_collateral_account.owner == user.key(),
+        constraint = user_collateral_account.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ReducePosition>,
+    size: u64,
+    min_payout: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active,
+        ForwardError::MarketNotActive
+    );
+
+    require!(size > 0, ForwardError::PositionSizeExceedsLimit);
+    require!(
+        size <= ctx.accounts.position.size,
+        ForwardError::PositionSizeExceedsLimit
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+
+    let curve_params = ctx
+        .accounts
+        .market_config
+        .active_curve_params(&ctx.accounts.global_config, now);
+
+    // Read Polymarket price from oracle
+    let polymarket_price = oracle::read_price(&ctx.accounts.price_oracle)?;
+    require!(polymarket_price > 0, ForwardError::InvalidOracleData);
+
+    let position_size = ctx.accounts.position.size;
+    let direction = ctx.accounts.position.direction;
+
+    // Price the exit: K over the unwound exposure path and the proportional
+    // share of collateral released (same math as quote_close)
+    let quote = math::quote_close(
+        polymarket_price,
+        &ctx.accounts.pool_state,
+        &curve_params,
+        &ctx.accounts.position,
+        ctx.accounts.market_config.effective_fee_bps(&ctx.accounts.global_config),
+        size,
+    )?;
+    let CloseQuote {
+        exit_price,
+        payout,
+        fee,
+        user_payout,
+        released_collateral,
+        ..
+    } = quote;
+
+    // Slippage protection: the user receives at least what they signed for
+    require!(user_payout >= min_payout, ForwardError::MinPayoutNotMet);
+
+    // The exited slice also carries its share of the premium and opening fee paid
+    let released_premium = (ctx.accounts.position.premium_paid as i128 * size as i128
+        / position_size as i128) as i64;
+    let released_fee = (ctx.accounts.position.fee_paid as u128 * size as u128
+        / position_size as u128) as u64;
+
+    // Pay the user and the fee out of the vault
+    let market_config_key = ctx.accounts.market_config.key();
+    transfer_close_payout(
+        &ctx.accounts.token_program,
+        &ctx.accounts.collateral_vault,
+        &[b"collateral_vault", market_config_key.as_ref(), &[ctx.bumps.collateral_vault]],
+        &ctx.accounts.user_collateral_account,
+        &ctx.accounts.fee_vault,
+        &quote,
+    )?;
+
+    // Remove the exited size from the pool; LPs keep the released collateral
+    // and fund everything paid out of the vault
+    ctx.accounts.pool_state.unwind(
+        direction,
+        size,
+        quote.pool_collateral,
+        released_collateral,
+        payout,
+    )?;
+
+    // Shrink the remaining position; it keeps its original forward price
+    let position = &mut ctx.accounts.position;
+    position.size = position
+        .size
+        .checked_sub(size)
+        .ok_or(ForwardError::MathOverflow)?;
+    position.collateral_locked = position
+        .collateral_locked
+        .checked_sub(released_collateral)
+        .ok_or(ForwardError::MathOverflow)?;
+    position.premium_paid = position
+        .premium_paid
+        .checked_sub(released_premium)
+        .ok_or(ForwardError::MathOverflow)?;
+    position.fee_paid = position
+        .fee_paid
+        .checked_sub(released_fee)
+        .ok_or(ForwardError::MathOverflow)?;
+
+    // Reducing the full size is equivalent to closing the position
+    if position.size == 0 {
+        position.status = PositionStatus::Closed;
+    }
+
+    emit_cpi!(PositionClosed {
+        position: position.key(),
+        owner: position.owner,
+        market: position.market,
+        direction,
+        size,
+        exit_price,
+        payout,
+        fee,
+        released_collateral,
+        remaining_size: position.size,
+        oracle_price: polymarket_price,
+        oracle_timestamp: ctx.accounts.price_oracle.timestamp,
+        total_long_exposure: ctx.accounts.pool_state.total_long_exposure,
+        total_short_exposure: ctx.accounts.pool_state.total_short_exposure,
+        timestamp: now,
+    });
+
+    msg!(
+        "Position reduced: exited {} at K={}, payout={}, fee={}, remaining={}",
+        size,
+        exit_price,
+        payout,
+        fee,
+        position.size
+    );
+
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{MarketStatus, PoolState, Position, PositionStatus};
+use crate::errors::ForwardError;
+use crate::events::PositionRefunded;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        mut,
+        constraint = position.owner == user.key(),
+        constraint = position.market == market_config.key(),
+        constraint = position.status == PositionStatus::Open @ ForwardError::PositionAlreadySettled
+    )]
+    pub position: Account<'info, crate::state::Position>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_collateral_account.owner == user.key(),
+        constraint = user_collateral_account.mint == collateral_vault.mint @ ForwardError::InvalidMint
+    )]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RefundPosition>) -> Result<()> {
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Cancelled,
+        ForwardError::MarketNotCancelled
+    );
+
+    let refund = book_refund(&mut ctx.accounts.pool_state, &mut ctx.accounts.position)?;
+
+    if refund > 0 {
+        let refund_amount = refund as u64;
+        require!(
+            ctx.accounts.collateral_vault.amount >= refund_amount,
+            ForwardError::InsufficientCollateral
+        );
+
+        let market_config_key = ctx.accounts.market_config.key();
+        let seeds = &[
+            b"collateral_vault",
+            market_config_key.as_ref(),
+            &[ctx.bumps.collateral_vault],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.user_collateral_account.to_account_info(),
+            authority: ctx.accounts.collateral_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, refund_amount)?;
+    } else if refund < 0 {
+        // Premium received exceeds collateral: claw back the difference
+        let clawback_amount = refund.unsigned_abs();
+        require!(
+            ctx.accounts.user_collateral_account.amount >= clawback_amount,
+            ForwardError::InsufficientCollateral
+        );
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_collateral_account.to_account_info(),
+            to: ctx.accounts.collateral_vault.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, clawback_amount)?;
+    }
+
+    let position = &ctx.accounts.position;
+    emit_cpi!(PositionRefunded {
+        position: position.key(),
+        owner: position.owner,
+        market: position.market,
+        direction: position.direction,
+        size: position.size,
+        collateral: position.collateral_locked,
+        premium_paid: position.premium_paid,
+        refund,
+        total_long_exposure: ctx.accounts.pool_state.total_long_exposure,
+        total_short_exposure: ctx.accounts.pool_state.total_short_exposure,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Position refunded: collateral={}, premium={}, refund={}",
+        position.collateral_locked,
+        position.premium_paid,
+        refund
+    );
+
+    Ok(())
+}
+
+/// Remove a cancelled market's `position` from the pool and mark it refunded,
+/// returning what the user gets back
+///
+/// The user is owed their collateral and the premium they paid; premium they
+/// received is netted off against the collateral, and a negative refund is
+/// clawed back from them.
+pub(crate) fn book_refund(pool_state: &mut PoolState, position: &mut Position) -> Result<i64> {
+    require!(
+        position.status == PositionStatus::Open,
+        ForwardError::PositionAlreadySettled
+    );
+    let refund = (position.collateral_locked as i64)
+        .checked_add(position.premium_paid)
+        .ok_or(ForwardError::MathOverflow)?;
+
+    pool_state.refund(position)?;
+    position.status = PositionStatus::Cancelled;
+    Ok(refund)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Direction;
+
+    const LP_CAPITAL: u64 = 2_000_000;
+
+    /// A long of 1_000_000 with 500_000 collateral opened at `premium` into a
+    /// pool of LP_CAPITAL, and the market vault balance after it
+    fn opened(premium: i64) -> (PoolState, Position, u64) {
+        let position = Position {
+            owner: Pubkey::default(),
+            market: Pubkey::default(),
+            direction: Direction::Long,
+            size: 1_000_000,
+            forward_price: 5000,
+            collateral_locked: 500_000,
+            premium_paid: premium,
+            fee_paid: 1_500,
+            status: PositionStatus::Open,
+            bump: 0,
+        };
+        let mut pool_state = PoolState {
+            market: Pubkey::default(),
+            total_long_exposure: 0,
+            total_short_exposure: 0,
+            pool_collateral: 0,
+            position_counter: 0,
+            lp_nav: LP_CAPITAL,
+            bump: 0,
+        };
+        pool_state
+            .open(Direction::Long, position.size, position.size - position.collateral_locked, premium)
+            .unwrap();
+        let vault = (LP_CAPITAL + position.collateral_locked) as i64 + premium;
+        (pool_state, position, vault as u64)
+    }
+
+    /// Refund `premium`'s position, returning the refund and the vault after it
+    fn refunded(premium: i64) -> (i64, PoolState, Position, u64) {
+        let (mut pool_state, mut position, vault) = opened(premium);
+        let refund = book_refund(&mut pool_state, &mut position).unwrap();
+        let vault = (vault as i64 - refund) as u64;
+        (refund, pool_state, position, vault)
+    }
+
+    fn assert_unwound(pool_state: &PoolState, vault: u64) {
+        assert_eq!(pool_state.total_long_exposure, 0);
+        assert_eq!(pool_state.pool_collateral, 0);
+        assert_eq!(pool_state.lp_nav, LP_CAPITAL);
+        assert_eq!(vault, LP_CAPITAL);
+    }
+
+    #[test]
+    fn premium_paid_is_returned_with_the_collateral() {
+        let (refund, pool_state, position, vault) = refunded(20_000);
+        assert_eq!(refund, 520_000);
+        assert_eq!(position.status, PositionStatus::Cancelled);
+        assert_unwound(&pool_state, vault);
+    }
+
+    #[test]
+    fn premium_received_is_netted_off_the_collateral() {
+        let (refund, pool_state, position, vault) = refunded(-100_000);
+        assert_eq!(refund, 400_000);
+        assert_eq!(position.status, PositionStatus::Cancelled);
+        assert_unwound(&pool_state, vault);
+    }
+
+    #[test]
+    fn premium_received_beyond_the_collateral_is_clawed_back() {
+        let (refund, pool_state, position, vault) = refunded(-600_000);
+        assert_eq!(refund, -100_000);
+        assert_eq!(position.status, PositionStatus::Cancelled);
+        assert_unwound(&pool_state, vault);
+    }
+
+    #[test]
+    fn position_cannot_be_refunded_twice() {
+        let (_, mut pool_state, mut position, _) = refunded(20_000);
+        assert_eq!(
+            book_refund(&mut pool_state, &mut position).unwrap_err(),
+            ForwardError::PositionAlreadySettled.into()
+        );
+        assert_eq!(pool_state.lp_nav, LP_CAPITAL);
+        assert_eq!(position.status, PositionStatus::Cancelled);
+
+        // Nor can a position that already left the pool another way
+        let (mut pool_state, mut position, _) = opened(20_000);
+        position.status = PositionStatus::Closed;
+        assert!(book_refund(&mut pool_state, &mut position).is_err());
+    }
+}
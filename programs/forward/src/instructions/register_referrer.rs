@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::Referrer;
+use crate::events::ReferrerRegistered;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Referrer::LEN,
+        seeds = [b"referrer", authority.key().as_ref()],
+        bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+    
+    referrer.authority = ctx.accounts.authority.key();
+    // New referrers start in the base tier until the admin promotes them
+    referrer.tier = 0;
+    referrer.accrued = 0;
+    referrer.claimed = 0;
+    referrer.bump = ctx.bumps.referrer;
+    
+    emit_cpi!(ReferrerRegistered {
+        referrer: referrer.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Referrer registered: {}", referrer.authority);
+    
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::errors::ForwardError;
+use crate::events::AdminTransferred;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RenounceAdmin<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<RenounceAdmin>) -> Result<()> {
+    // Irreversible: no key can sign for the default pubkey, so admin-only
+    // instructions are disabled for good. Delegated roles are unaffected.
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.admin = Pubkey::default();
+    global_config.pending_admin = None;
+    
+    emit_cpi!(AdminTransferred {
+        previous_admin: ctx.accounts.admin.key(),
+        new_admin: Pubkey::default(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Admin renounced");
+    
+    Ok(())
+}
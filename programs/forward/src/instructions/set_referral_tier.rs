@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::errors::ForwardError;
+use crate::events::ReferralTierUpdated;
+use crate::math::BASIS_POINTS;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReferralTier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<SetReferralTier>, tier: u8, share_bps: u64) -> Result<()> {
+    require!(
+        (tier as usize) < GlobalConfig::MAX_REFERRAL_TIERS,
+        ForwardError::InvalidReferralTier
+    );
+    require!(
+        share_bps <= BASIS_POINTS,
+        ForwardError::InvalidReferralTier
+    );
+    
+    // Applies to fees charged from now on; already credited fees are unaffected
+    ctx.accounts.global_config.referral_tiers[tier as usize] = share_bps;
+    
+    emit_cpi!(ReferralTierUpdated {
+        tier,
+        share_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Referral tier {} set to {} bps of the trading fee", tier, share_bps);
+    
+    Ok(())
+}
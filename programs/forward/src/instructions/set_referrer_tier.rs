@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, Referrer};
+use crate::errors::ForwardError;
+use crate::events::ReferrerTierUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReferrerTier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"referrer", referrer.authority.as_ref()],
+        bump = referrer.bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+pub fn handler(ctx: Context<SetReferrerTier>, tier: u8) -> Result<()> {
+    require!(
+        (tier as usize) < GlobalConfig::MAX_REFERRAL_TIERS,
+        ForwardError::InvalidReferralTier
+    );
+    
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.tier = tier;
+    
+    emit_cpi!(ReferrerTierUpdated {
+        referrer: referrer.authority,
+        tier,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Referrer {} moved to tier {}", referrer.authority, tier);
+    
+    Ok(())
+}
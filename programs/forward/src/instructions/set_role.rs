@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::Role;
+use crate::errors::ForwardError;
+use crate::events::RoleUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetRole>,
+    role: Role,
+    authority: Pubkey,
+) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    
+    match role {
+        Role::PriceKeeper => global_config.price_keeper = authority,
+        Role::ResolutionKeeper => global_config.resolution_keeper = authority,
+        Role::RiskManager => global_config.risk_manager = authority,
+    }
+    
+    emit_cpi!(RoleUpdated {
+        role,
+        authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Role {:?} set to {}", role, authority);
+    
+    Ok(())
+}
@@ -3,7 +3,9 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::{MarketStatus, PositionStatus};
 use crate::math;
 use crate::errors::ForwardError;
+use crate::events::PositionSettled;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SettlePosition<'info> {
     #[account(mut)]
@@ -55,7 +57,7 @@ pub struct SettlePosition<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<SettlePosition>) -> Result<()> {
+pub fn handler(ctx: Context<SettlePosition>, close: bool) -> Result<()> {
     require!(
         ctx.accounts.market_config.status == MarketStatus::Resolved,
         ForwardError::MarketNotResolved
@@ -72,66 +74,37 @@ pub fn handler(ctx: Context<SettlePosition>) -> Result<()> {
         outcome,
     );
     
-    // Calculate total collateral locked for this position
-    // User collateral + pool collateral = size (Q)
-    let total_collateral = ctx.accounts.position.size;
-    
-    // Calculate pool collateral for this position
-    let pool_collateral = total_collateral
-        .checked_sub(ctx.accounts.position.collateral_locked)
-        .ok_or(ForwardError::MathOverflow)?;
-    
     // Transfer payout to user
-    if payout > 0 {
-        require!(
-            ctx.accounts.collateral_vault.amount >= payout,
-            ForwardError::InsufficientCollateral
-        );
-        
-        let market_config_key = ctx.accounts.market_config.key();
-        let seeds = &[
-            b"collateral_vault",
-            market_config_key.as_ref(),
-            &[ctx.bumps.collateral_vault],
-        ];
-        let signer = &[&seeds[..]];
-        
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.collateral_vault.to_account_info(),
-            to: ctx.accounts.user_collateral_account.to_account_info(),
-            authority: ctx.accounts.collateral_vault.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, payout)?;
-    }
-    
-    // Update pool state - reduce exposure
-    let pool_state = &mut ctx.accounts.pool_state;
-    match ctx.accounts.position.direction {
-        crate::state::Direction::Long => {
-            pool_state.total_long_exposure = pool_state
-                .total_long_exposure
-                .checked_sub(ctx.accounts.position.size)
-                .ok_or(ForwardError::MathOverflow)?;
-        }
-        crate::state::Direction::Short => {
-            pool_state.total_short_exposure = pool_state
-                .total_short_exposure
-                .checked_sub(ctx.accounts.position.size)
-                .ok_or(ForwardError::MathOverflow)?;
-        }
-    }
+    let market_config_key = ctx.accounts.market_config.key();
+    transfer_settlement_payout(
+        &ctx.accounts.token_program,
+        &ctx.accounts.collateral_vault,
+        &[b"collateral_vault", market_config_key.as_ref(), &[ctx.bumps.collateral_vault]],
+        ctx.accounts.user_collateral_account.to_account_info(),
+        payout,
+    )?;
     
-    // Reduce pool collateral
-    pool_state.pool_collateral = pool_state
-        .pool_collateral
-        .checked_sub(pool_collateral)
-        .ok_or(ForwardError::MathOverflow)?;
+    // Release the position's exposure and collateral from the pool
+    ctx.accounts.pool_state.settle(&ctx.accounts.position, payout)?;
     
     // Mark position as settled
     ctx.accounts.position.status = PositionStatus::Settled;
     
+    let position = &ctx.accounts.position;
+    emit_cpi!(PositionSettled {
+        position: position.key(),
+        owner: position.owner,
+        market: position.market,
+        direction: position.direction,
+        size: position.size,
+        forward_price: position.forward_price,
+        payout_fraction: outcome.payout_fraction(),
+        payout,
+        total_long_exposure: ctx.accounts.pool_state.total_long_exposure,
+        total_short_exposure: ctx.accounts.pool_state.total_short_exposure,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     msg!(
         "Position settled: outcome={:?}, payout={}, size={}",
         outcome,
@@ -139,6 +112,38 @@ pub fn handler(ctx: Context<SettlePosition>) -> Result<()> {
         ctx.accounts.position.size
     );
     
+    // Optionally return the position account's rent to the owner
+    if close {
+        ctx.accounts.position.close(ctx.accounts.user.to_account_info())?;
+    }
+    
     Ok(())
 }
 
+/// Pay a settled position's `payout` out of the market vault to its owner
+///
+/// Shared by `settle_position` and `crank_settle`.
+pub(crate) fn transfer_settlement_payout<'info>(
+    token_program: &Program<'info, Token>,
+    collateral_vault: &Account<'info, TokenAccount>,
+    collateral_vault_seeds: &[&[u8]],
+    owner_collateral_account: AccountInfo<'info>,
+    payout: u64,
+) -> Result<()> {
+    if payout == 0 {
+        return Ok(());
+    }
+    require!(
+        collateral_vault.amount >= payout,
+        ForwardError::InsufficientCollateral
+    );
+
+    let signer = &[collateral_vault_seeds];
+    let cpi_accounts = Transfer {
+        from: collateral_vault.to_account_info(),
+        to: owner_collateral_account,
+        authority: collateral_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, payout)
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::errors::ForwardError;
+use crate::events::CrankFeeUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateCrankFee<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateCrankFee>, crank_fee: u64) -> Result<()> {
+    // Paid per settled position out of protocol fees; 0 disables it. No
+    // absolute bound: crank_settle caps each payout at CRANK_FEE_SHARE_BPS of
+    // the position's trading fee, which holds whatever the mint's decimals
+    ctx.accounts.global_config.crank_fee = crank_fee;
+
+    emit_cpi!(CrankFeeUpdated {
+        crank_fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Crank fee set to {} per settled position", crank_fee);
+
+    Ok(())
+}
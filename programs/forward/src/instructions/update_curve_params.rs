@@ -1,18 +1,19 @@
 use anchor_lang::prelude::*;
-use crate::state::CurveParams;
+use crate::state::{CurveParams, PendingCurveParams};
 use crate::errors::ForwardError;
-use crate::math::BASIS_POINTS;
+use crate::events::CurveParamsUpdated;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateCurveParams<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub risk_manager: Signer<'info>,
     
     #[account(
         mut,
         seeds = [b"global_config"],
         bump = global_config.bump,
-        has_one = admin @ ForwardError::Unauthorized
+        has_one = risk_manager @ ForwardError::Unauthorized
     )]
     pub global_config: Account<'info, crate::state::GlobalConfig>,
 }
@@ -22,23 +23,31 @@ pub fn handler(
     curve_params: CurveParams,
 ) -> Result<()> {
     // Validate updated curve parameters with same invariants as init_global_config
-    require!(
-        curve_params.min_price <= curve_params.max_price,
-        ForwardError::InvalidOracleData
-    );
-    require!(
-        curve_params.max_price <= BASIS_POINTS,
-        ForwardError::InvalidOracleData
-    );
-    require!(
-        curve_params.max_exposure > 0,
-        ForwardError::InvalidOracleData
-    );
+    curve_params.validate()?;
 
+    // Persist a queued change whose timelock has elapsed before replacing it;
+    // trading instructions only read it
     let global_config = &mut ctx.accounts.global_config;
-    global_config.curve_params = curve_params;
+    let now = Clock::get()?.unix_timestamp;
+    global_config.apply_pending_curve_params(now);
     
-    msg!("Curve parameters updated");
+    // Queue the change behind the timelock
+    let activates_at = now
+        .checked_add(global_config.active_curve_update_delay(now))
+        .ok_or(ForwardError::MathOverflow)?;
+    global_config.pending_curve_params = Some(PendingCurveParams {
+        curve_params: curve_params.clone(),
+        activates_at,
+    });
+    
+    emit_cpi!(CurveParamsUpdated {
+        market: None,
+        curve_params: Some(curve_params),
+        activates_at,
+        timestamp: now,
+    });
+    
+    msg!("Curve parameters update queued, activates at {}", activates_at);
     
     Ok(())
 }
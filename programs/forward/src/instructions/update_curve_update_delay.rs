@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, PendingCurveUpdateDelay};
+use crate::errors::ForwardError;
+use crate::events::CurveUpdateDelayUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateCurveUpdateDelay<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateCurveUpdateDelay>,
+    curve_update_delay: i64,
+) -> Result<()> {
+    require!(
+        (0..=GlobalConfig::MAX_CURVE_UPDATE_DELAY).contains(&curve_update_delay),
+        ForwardError::InvalidCurveUpdateDelay
+    );
+    
+    // Persist a queued reduction that has taken effect; a newer update
+    // replaces one that is still waiting
+    let now = Clock::get()?.unix_timestamp;
+    let global_config = &mut ctx.accounts.global_config;
+    let current_delay = global_config.active_curve_update_delay(now);
+    global_config.curve_update_delay = current_delay;
+    global_config.pending_curve_update_delay = None;
+    
+    // Applies to changes queued from now on; a pending change keeps its activation time.
+    // A longer delay only slows changes down, so it applies at once. A shorter one
+    // waits out the current delay, so it cannot be used to skip the timelock.
+    let activates_at = if curve_update_delay >= current_delay {
+        global_config.curve_update_delay = curve_update_delay;
+        now
+    } else {
+        let activates_at = now
+            .checked_add(current_delay)
+            .ok_or(ForwardError::MathOverflow)?;
+        global_config.pending_curve_update_delay = Some(PendingCurveUpdateDelay {
+            curve_update_delay,
+            activates_at,
+        });
+        activates_at
+    };
+    
+    emit_cpi!(CurveUpdateDelayUpdated {
+        curve_update_delay,
+        activates_at,
+        timestamp: now,
+    });
+    
+    msg!(
+        "Curve update delay set to {}s, effective at {}",
+        curve_update_delay,
+        activates_at
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::errors::ForwardError;
+use crate::events::FeeUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateFeeBps<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateFeeBps>, fee_bps: u64) -> Result<()> {
+    require!(
+        fee_bps <= GlobalConfig::MAX_FEE_BPS,
+        ForwardError::FeeTooHigh
+    );
+    
+    // Markets with their own fee_bps override are unaffected
+    ctx.accounts.global_config.fee_bps = fee_bps;
+    
+    emit_cpi!(FeeUpdated {
+        market: None,
+        fee_bps: Some(fee_bps),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Trading fee set to {} bps", fee_bps);
+    
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{CurveParams, PendingMarketCurveParams};
+use crate::errors::ForwardError;
+use crate::events::CurveParamsUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMarketCurveParams<'info> {
+    #[account(mut)]
+    pub risk_manager: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = risk_manager @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateMarketCurveParams>,
+    curve_params: Option<CurveParams>,
+) -> Result<()> {
+    // Validate with same invariants as update_curve_params; None removes the
+    // override, falling back to the global curve
+    if let Some(curve_params) = &curve_params {
+        curve_params.validate()?;
+    }
+
+    // Persist a queued change whose timelock has elapsed before replacing it;
+    // trading instructions only read it
+    let now = Clock::get()?.unix_timestamp;
+    let market_config = &mut ctx.accounts.market_config;
+    market_config.apply_pending_curve_params(now);
+
+    // Queue the change behind the global timelock
+    let activates_at = now
+        .checked_add(ctx.accounts.global_config.active_curve_update_delay(now))
+        .ok_or(ForwardError::MathOverflow)?;
+    market_config.pending_curve_params = Some(PendingMarketCurveParams {
+        curve_params: curve_params.clone(),
+        activates_at,
+    });
+    
+    emit_cpi!(CurveParamsUpdated {
+        market: Some(market_config.key()),
+        curve_params,
+        activates_at,
+        timestamp: now,
+    });
+    
+    msg!(
+        "Market curve parameters update queued for {}, activates at {}",
+        market_config.polymarket_market_id,
+        activates_at
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::errors::ForwardError;
+use crate::events::FeeUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMarketFeeBps<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+}
+
+pub fn handler(ctx: Context<UpdateMarketFeeBps>, fee_bps: Option<u64>) -> Result<()> {
+    if let Some(fee_bps) = fee_bps {
+        require!(
+            fee_bps <= GlobalConfig::MAX_FEE_BPS,
+            ForwardError::FeeTooHigh
+        );
+    }
+    
+    let market_config = &mut ctx.accounts.market_config;
+    market_config.fee_bps = fee_bps;
+    
+    emit_cpi!(FeeUpdated {
+        market: Some(market_config.key()),
+        fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!(
+        "Trading fee for {} set to {:?} bps",
+        market_config.polymarket_market_id,
+        fee_bps
+    );
+    
+    Ok(())
+}
@@ -1,58 +1,134 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::{MarketStatus, Outcome};
+use crate::oracle::ResolutionStatus;
 use crate::errors::ForwardError;
+use crate::math::BASIS_POINTS;
+use crate::events::MarketResolved;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateMarketResolution<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
-    
+    pub resolution_keeper: Signer<'info>,
+
     #[account(
         seeds = [b"global_config"],
         bump = global_config.bump,
-        has_one = admin @ ForwardError::Unauthorized
+        has_one = resolution_keeper @ ForwardError::Unauthorized
     )]
     pub global_config: Account<'info, crate::state::GlobalConfig>,
-    
+
     #[account(
         mut,
         seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
         bump = market_config.bump
     )]
     pub market_config: Account<'info, crate::state::MarketConfig>,
-    
+
     #[account(
         mut,
         seeds = [b"resolution_oracle", market_config.key().as_ref()],
         bump
     )]
     pub resolution_oracle: Account<'info, crate::oracle::ResolutionOracle>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    /// Collateral account of the dispute winner (proposer or disputer)
+    #[account(
+        mut,
+        constraint = winner_collateral_account.mint == bond_vault.mint @ ForwardError::InvalidMint
+    )]
+    pub winner_collateral_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(
     ctx: Context<UpdateMarketResolution>,
     outcome: Outcome,
 ) -> Result<()> {
-    let market_config = &mut ctx.accounts.market_config;
-    
     require!(
-        market_config.status == MarketStatus::Active || market_config.status == MarketStatus::TradingClosed,
+        ctx.accounts.market_config.status != MarketStatus::Resolved,
+        ForwardError::MarketAlreadyResolved
+    );
+
+    require!(
+        ctx.accounts.market_config.status == MarketStatus::Active || ctx.accounts.market_config.status == MarketStatus::TradingClosed,
         ForwardError::InvalidMarketStatus
     );
-    
+
+    // Only disputed proposals go to arbitration; undisputed ones finalize on their own
     require!(
-        market_config.status != MarketStatus::Resolved,
-        ForwardError::MarketAlreadyResolved
+        ctx.accounts.resolution_oracle.status == ResolutionStatus::Disputed,
+        ForwardError::InvalidResolutionState
+    );
+
+    // Split outcomes carry a YES payout fraction in basis points
+    require!(
+        outcome.payout_fraction() <= BASIS_POINTS,
+        ForwardError::InvalidOracleData
+    );
+
+    // The side whose outcome is upheld receives both bonds
+    let winner = if outcome.payout_fraction() == ctx.accounts.resolution_oracle.proposed_payout_fraction {
+        ctx.accounts.resolution_oracle.proposer
+    } else {
+        ctx.accounts.resolution_oracle.disputer
+    };
+    require!(
+        ctx.accounts.winner_collateral_account.owner == winner,
+        ForwardError::Unauthorized
     );
-    
+
+    let bonds = ctx
+        .accounts
+        .resolution_oracle
+        .bond
+        .checked_mul(2)
+        .ok_or(ForwardError::MathOverflow)?;
+    if bonds > 0 {
+        let market_config_key = ctx.accounts.market_config.key();
+        let seeds = &[
+            b"bond_vault",
+            market_config_key.as_ref(),
+            &[ctx.bumps.bond_vault],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.bond_vault.to_account_info(),
+            to: ctx.accounts.winner_collateral_account.to_account_info(),
+            authority: ctx.accounts.bond_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, bonds)?;
+    }
+
     // Persist outcome in the per‑market resolution oracle PDA
-    ctx.accounts.resolution_oracle.outcome = Some(outcome.as_u8());
-    ctx.accounts.resolution_oracle.resolved_at = Some(Clock::get()?.unix_timestamp);
+    let clock = Clock::get()?;
+    let resolution_oracle = &mut ctx.accounts.resolution_oracle;
+    resolution_oracle.payout_fraction = Some(outcome.payout_fraction());
+    resolution_oracle.resolved_at = Some(clock.unix_timestamp);
+    resolution_oracle.status = ResolutionStatus::Resolved;
+
+    ctx.accounts.market_config.status = MarketStatus::Resolved;
+
+    emit_cpi!(MarketResolved {
+        market: ctx.accounts.market_config.key(),
+        payout_fraction: outcome.payout_fraction(),
+        disputed: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Market resolved with outcome: {:?} (bonds to {})", outcome, winner);
 
-    market_config.status = MarketStatus::Resolved;
-    
-    msg!("Market resolved with outcome: {:?}", outcome);
-    
     Ok(())
 }
-
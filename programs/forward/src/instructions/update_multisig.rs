@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::Multisig;
+use crate::errors::ForwardError;
+use crate::events::MultisigUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    
+    /// Only reachable through an executed proposal
+    #[account(
+        seeds = [b"multisig_signer", multisig.key().as_ref()],
+        bump = multisig.signer_bump
+    )]
+    pub multisig_signer: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateMultisig>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    Multisig::validate_config(&members, threshold)?;
+    
+    // Approvals are indexed by member, so every pending proposal goes stale
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.config_version = multisig
+        .config_version
+        .checked_add(1)
+        .ok_or(ForwardError::MathOverflow)?;
+    
+    emit_cpi!(MultisigUpdated {
+        multisig: multisig.key(),
+        members: multisig.members.clone(),
+        threshold,
+        config_version: multisig.config_version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!(
+        "Multisig updated: {}-of-{}",
+        threshold,
+        multisig.members.len()
+    );
+    
+    Ok(())
+}
@@ -2,16 +2,18 @@ use anchor_lang::prelude::*;
 use crate::state::MarketConfig;
 use crate::errors::ForwardError;
 use crate::math::BASIS_POINTS;
+use crate::events::PriceUpdated;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdatePriceOracle<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub price_keeper: Signer<'info>,
     
     #[account(
         seeds = [b"global_config"],
         bump = global_config.bump,
-        has_one = admin @ ForwardError::Unauthorized
+        has_one = price_keeper @ ForwardError::Unauthorized
     )]
     pub global_config: Account<'info, crate::state::GlobalConfig>,
     
@@ -49,6 +51,13 @@ pub fn handler(
     price_oracle.timestamp = clock.unix_timestamp;
     price_oracle.exponent = exponent;
     
+    emit_cpi!(PriceUpdated {
+        market: ctx.accounts.market_config.key(),
+        price,
+        exponent,
+        timestamp: clock.unix_timestamp,
+    });
+    
     msg!(
         "Price oracle updated: price={} bps, timestamp={}, exponent={}",
         price,
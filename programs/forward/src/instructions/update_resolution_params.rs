@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::ResolutionParams;
+use crate::errors::ForwardError;
+use crate::events::ResolutionParamsUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateResolutionParams<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateResolutionParams>,
+    resolution_params: ResolutionParams,
+) -> Result<()> {
+    resolution_params.validate()?;
+
+    // Proposals already in flight keep the bond and deadline they were made with
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.resolution_params = resolution_params.clone();
+    
+    emit_cpi!(ResolutionParamsUpdated {
+        resolution_params,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Resolution parameters updated");
+    
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::FeeLedger;
+use crate::errors::ForwardError;
+use crate::events::FeesWithdrawn;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        has_one = admin @ ForwardError::Unauthorized
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"fee_ledger"],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Account<'info, crate::state::FeeLedger>,
+    
+    #[account(
+        mut,
+        constraint = treasury.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    check_fee_withdrawal(&ctx.accounts.fee_ledger, ctx.accounts.fee_vault.amount, amount)?;
+    
+    let seeds = &[b"fee_vault".as_ref(), &[ctx.bumps.fee_vault]];
+    let signer = &[&seeds[..]];
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.fee_vault.to_account_info(),
+        to: ctx.accounts.treasury.to_account_info(),
+        authority: ctx.accounts.fee_vault.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+    
+    emit_cpi!(FeesWithdrawn {
+        admin: ctx.accounts.admin.key(),
+        treasury: ctx.accounts.treasury.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Withdrew {} in fees to {}", amount, ctx.accounts.treasury.key());
+    
+    Ok(())
+}
+
+/// Require `amount` to be a positive withdrawal from the protocol's part of a
+/// fee vault holding `fee_vault_amount`
+///
+/// Fees credited to referrers stay in the vault until they claim them.
+pub(crate) fn check_fee_withdrawal(fee_ledger: &FeeLedger, fee_vault_amount: u64, amount: u64) -> Result<()> {
+    require!(
+        amount > 0 && amount <= fee_ledger.protocol_fees(fee_vault_amount),
+        ForwardError::InsufficientCollateral
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(referral_fees_owed: u64) -> FeeLedger {
+        FeeLedger {
+            referral_fees_owed,
+            bump: 0,
+        }
+    }
+
+    fn rejected(fee_ledger: &FeeLedger, fee_vault_amount: u64, amount: u64) -> bool {
+        check_fee_withdrawal(fee_ledger, fee_vault_amount, amount).unwrap_err()
+            == ForwardError::InsufficientCollateral.into()
+    }
+
+    #[test]
+    fn referral_fees_owed_stay_in_the_vault() {
+        // 1_000 collected, 300 of it credited to a referrer
+        let mut fee_ledger = ledger(300);
+        assert!(check_fee_withdrawal(&fee_ledger, 1_000, 700).is_ok());
+        assert!(rejected(&fee_ledger, 1_000, 701));
+        assert!(rejected(&fee_ledger, 1_000, 0));
+
+        // After the protocol takes its 700 the rest is the referrer's
+        assert!(rejected(&fee_ledger, 300, 1));
+
+        // Once the referrer claims, new fees are the protocol's again
+        fee_ledger.referral_fees_owed -= 300;
+        assert!(check_fee_withdrawal(&fee_ledger, 50, 50).is_ok());
+    }
+
+    #[test]
+    fn nothing_is_withdrawable_while_referrers_are_owed_the_whole_vault() {
+        assert!(rejected(&ledger(500), 500, 1));
+        assert!(rejected(&ledger(800), 500, 1));
+    }
+}
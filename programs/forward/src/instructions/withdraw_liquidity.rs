@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::PoolState;
+use crate::errors::ForwardError;
+use crate::events::LiquidityWithdrawn;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawLiquidity<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, crate::state::GlobalConfig>,
+
+    #[account(
+        seeds = [b"market", market_config.polymarket_market_id.as_bytes()],
+        bump = market_config.bump
+    )]
+    pub market_config: Account<'info, crate::state::MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_state", market_config.key().as_ref()],
+        bump = pool_state.bump
+    )]
+    pub pool_state: Account<'info, crate::state::PoolState>,
+
+    #[account(
+        mut,
+        seeds = [b"collateral_vault", market_config.key().as_ref()],
+        bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"lp_mint", market_config.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_collateral_account.owner == user.key(),
+        constraint = user_collateral_account.mint == global_config.collateral_mint @ ForwardError::InvalidMint
+    )]
+    pub user_collateral_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_lp_account.owner == user.key(),
+        constraint = user_lp_account.mint == lp_mint.key() @ ForwardError::InvalidMint
+    )]
+    pub user_lp_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawLiquidity>, shares: u64) -> Result<()> {
+    require!(shares > 0, ForwardError::InsufficientCollateral);
+
+    require!(
+        ctx.accounts.user_lp_account.amount >= shares,
+        ForwardError::InsufficientCollateral
+    );
+
+    let amount = book_withdrawal(
+        &mut ctx.accounts.pool_state,
+        ctx.accounts.lp_mint.supply,
+        shares,
+    )?;
+
+    // Burn LP shares
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: ctx.accounts.user_lp_account.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::burn(cpi_ctx, shares)?;
+
+    // Transfer collateral from vault to LP
+    if amount > 0 {
+        let market_config_key = ctx.accounts.market_config.key();
+        let seeds = &[
+            b"collateral_vault",
+            market_config_key.as_ref(),
+            &[ctx.bumps.collateral_vault],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.collateral_vault.to_account_info(),
+            to: ctx.accounts.user_collateral_account.to_account_info(),
+            authority: ctx.accounts.collateral_vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+    }
+
+    let pool_state = &ctx.accounts.pool_state;
+    emit_cpi!(LiquidityWithdrawn {
+        market: ctx.accounts.market_config.key(),
+        provider: ctx.accounts.user.key(),
+        shares,
+        amount,
+        lp_nav: pool_state.lp_nav,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Liquidity withdrawn: shares={}, amount={}, nav={}",
+        shares,
+        amount,
+        pool_state.lp_nav
+    );
+
+    Ok(())
+}
+
+/// Book the redemption of `shares` out of the `supply` LP shares, returning
+/// the collateral they are worth
+///
+/// Shares are redeemed at the current NAV per share, and only capital not
+/// locked against open positions can leave the pool.
+pub(crate) fn book_withdrawal(pool_state: &mut PoolState, supply: u64, shares: u64) -> Result<u64> {
+    let amount = (shares as u128 * pool_state.lp_nav as u128 / supply as u128) as u64;
+    require!(
+        amount <= pool_state.free_liquidity(),
+        ForwardError::InsufficientLiquidity
+    );
+
+    pool_state.lp_nav = pool_state
+        .lp_nav
+        .checked_sub(amount)
+        .ok_or(ForwardError::MathOverflow)?;
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(lp_nav: u64, pool_collateral: u64) -> PoolState {
+        PoolState {
+            market: Pubkey::default(),
+            total_long_exposure: 0,
+            total_short_exposure: 0,
+            pool_collateral,
+            position_counter: 0,
+            lp_nav,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn shares_redeem_at_nav_per_share() {
+        let mut pool_state = pool(3_000, 0);
+        assert_eq!(book_withdrawal(&mut pool_state, 2_000, 500).unwrap(), 750);
+        assert_eq!(pool_state.lp_nav, 2_250);
+
+        // The last shares out take whatever rounding left behind
+        assert_eq!(book_withdrawal(&mut pool_state, 1_500, 1_499).unwrap(), 2_248);
+        assert_eq!(book_withdrawal(&mut pool_state, 1, 1).unwrap(), 2);
+        assert_eq!(pool_state.lp_nav, 0);
+    }
+
+    #[test]
+    fn withdrawals_are_capped_at_free_liquidity() {
+        let mut pool_state = pool(1_000, 600);
+        assert_eq!(
+            book_withdrawal(&mut pool_state, 1_000, 401).unwrap_err(),
+            ForwardError::InsufficientLiquidity.into()
+        );
+        assert_eq!(pool_state.lp_nav, 1_000);
+
+        assert_eq!(book_withdrawal(&mut pool_state, 1_000, 400).unwrap(), 400);
+        assert_eq!(pool_state.free_liquidity(), 0);
+        assert_eq!(
+            book_withdrawal(&mut pool_state, 600, 1).unwrap_err(),
+            ForwardError::InsufficientLiquidity.into()
+        );
+    }
+}
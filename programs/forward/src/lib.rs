@@ -3,10 +3,13 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod math;
 pub mod oracle;
 pub mod state;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 
 use instructions::*;
 
@@ -20,8 +23,11 @@ pub mod forward {
     pub fn init_global_config(
         ctx: Context<InitGlobalConfig>,
         curve_params: state::CurveParams,
+        curve_update_delay: i64,
+        resolution_params: state::ResolutionParams,
+        fee_bps: u64,
     ) -> Result<()> {
-        instructions::init_global_config::handler(ctx, curve_params)
+        instructions::init_global_config::handler(ctx, curve_params, curve_update_delay, resolution_params, fee_bps)
     }
 
     /// Create a new market linked to a Polymarket market
@@ -30,11 +36,13 @@ pub mod forward {
         polymarket_market_id: String,
         resolution_timestamp: i64,
         risk_limits: state::RiskLimits,
+        curve_params: Option<state::CurveParams>, // overrides the global curve for this market
+        fee_bps: Option<u64>,                     // overrides the global fee for this market
     ) -> Result<()> {
-        instructions::create_market::handler(ctx, polymarket_market_id, resolution_timestamp, risk_limits)
+        instructions::create_market::handler(ctx, polymarket_market_id, resolution_timestamp, risk_limits, curve_params, fee_bps)
     }
 
-    /// Update curve parameters for a market
+    /// Queue new curve parameters behind the timelock (risk manager)
     pub fn update_curve_params(
         ctx: Context<UpdateCurveParams>,
         curve_params: state::CurveParams,
@@ -42,27 +50,280 @@ pub mod forward {
         instructions::update_curve_params::handler(ctx, curve_params)
     }
 
-    /// Close market for trading (before resolution)
+    /// Queue a per-market curve parameter override, or its removal, behind the timelock (risk manager)
+    pub fn update_market_curve_params(
+        ctx: Context<UpdateMarketCurveParams>,
+        curve_params: Option<state::CurveParams>,
+    ) -> Result<()> {
+        instructions::update_market_curve_params::handler(ctx, curve_params)
+    }
+
+    /// Cancel a queued curve parameter change
+    pub fn cancel_curve_params_update(ctx: Context<CancelCurveParamsUpdate>) -> Result<()> {
+        instructions::cancel_curve_params_update::handler(ctx)
+    }
+
+    /// Cancel a queued per-market curve parameter override (admin)
+    pub fn cancel_market_curve_params_update(ctx: Context<CancelMarketCurveParamsUpdate>) -> Result<()> {
+        instructions::cancel_market_curve_params_update::handler(ctx)
+    }
+
+    /// Set the timelock delay for curve parameter changes
+    pub fn update_curve_update_delay(
+        ctx: Context<UpdateCurveUpdateDelay>,
+        curve_update_delay: i64,
+    ) -> Result<()> {
+        instructions::update_curve_update_delay::handler(ctx, curve_update_delay)
+    }
+
+    /// Assign an operational role (price keeper, resolution keeper, risk manager)
+    pub fn set_role(
+        ctx: Context<SetRole>,
+        role: state::Role,
+        authority: Pubkey,
+    ) -> Result<()> {
+        instructions::set_role::handler(ctx, role, authority)
+    }
+
+    /// Propose a new admin, who must accept before the transfer takes effect
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        instructions::propose_admin::handler(ctx, new_admin)
+    }
+
+    /// Accept a pending admin transfer
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::handler(ctx)
+    }
+
+    /// Permanently give up the admin role
+    pub fn renounce_admin(ctx: Context<RenounceAdmin>) -> Result<()> {
+        instructions::renounce_admin::handler(ctx)
+    }
+
+    /// Create the M-of-N multisig whose signer PDA can hold the admin or any role
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_multisig::handler(ctx, members, threshold)
+    }
+
+    /// Propose a forward instruction to be signed by the multisig
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        accounts: Vec<state::ProposalAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::create_proposal::handler(ctx, accounts, data)
+    }
+
+    /// Approve a multisig proposal
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal::handler(ctx)
+    }
+
+    /// Execute an approved proposal, signing with the multisig signer PDA
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_proposal::handler(ctx)
+    }
+
+    /// Replace the multisig members and threshold (multisig signer, via a proposal)
+    pub fn update_multisig(
+        ctx: Context<UpdateMultisig>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::update_multisig::handler(ctx, members, threshold)
+    }
+
+    /// Close market for trading before resolution (risk manager)
     pub fn close_market_for_trading(ctx: Context<CloseMarketForTrading>) -> Result<()> {
         instructions::close_market_for_trading::handler(ctx)
     }
 
+    /// Cancel a market so open positions can be refunded (admin, or the resolution keeper for a disputed market)
+    pub fn cancel_market(ctx: Context<CancelMarket>) -> Result<()> {
+        instructions::cancel_market::handler(ctx)
+    }
+
+    /// Close a resolved or cancelled market once it is empty, returning rent to the admin
+    pub fn close_market(ctx: Context<CloseMarket>) -> Result<()> {
+        instructions::close_market::handler(ctx)
+    }
+
     /// Open a position (long or short)
     pub fn open_position(
         ctx: Context<OpenPosition>,
         direction: state::Direction,
         size: u64,
         slippage_tolerance: Option<u64>, // in basis points (10000 = 100%)
+        limit_forward_price: Option<u64>, // max K for longs, min K for shorts (basis points)
+        max_premium: Option<i64>,         // maximum premium paid (negative: minimum received)
+        expires_at: Option<i64>,          // unix timestamp after which the order is rejected
+    ) -> Result<()> {
+        instructions::open_position::handler(
+            ctx,
+            direction,
+            size,
+            slippage_tolerance,
+            limit_forward_price,
+            max_premium,
+            expires_at,
+        )
+    }
+
+    /// Add size to an existing open position in the same direction
+    pub fn increase_position(
+        ctx: Context<IncreasePosition>,
+        size: u64,
+        slippage_tolerance: Option<u64>, // in basis points (10000 = 100%)
+        limit_forward_price: Option<u64>, // max K for longs, min K for shorts (basis points)
+        max_premium: Option<i64>,         // maximum premium paid (negative: minimum received)
+        expires_at: Option<i64>,          // unix timestamp after which the order is rejected
     ) -> Result<()> {
-        instructions::open_position::handler(ctx, direction, size, slippage_tolerance)
+        instructions::increase_position::handler(
+            ctx,
+            size,
+            slippage_tolerance,
+            limit_forward_price,
+            max_premium,
+            expires_at,
+        )
+    }
+
+    /// Preview opening a position: K, premium, collateral and max size (read-only)
+    pub fn quote_open(
+        ctx: Context<QuoteOpen>,
+        direction: state::Direction,
+        size: u64,
+    ) -> Result<state::Quote> {
+        instructions::quote_open::handler(ctx, direction, size)
+    }
+
+    /// Preview closing or reducing a position: exit K and payout (read-only)
+    pub fn quote_close(
+        ctx: Context<QuoteClose>,
+        size: Option<u64>, // None quotes the whole position
+    ) -> Result<state::CloseQuote> {
+        instructions::quote_close::handler(ctx, size)
     }
 
-    /// Settle a position after market resolution
-    pub fn settle_position(ctx: Context<SettlePosition>) -> Result<()> {
-        instructions::settle_position::handler(ctx)
+    /// Settle a position after market resolution, optionally closing the account
+    pub fn settle_position(ctx: Context<SettlePosition>, close: bool) -> Result<()> {
+        instructions::settle_position::handler(ctx, close)
     }
 
-    /// Update market resolution from oracle (keeper function)
+    /// Close a settled, closed or refunded position and return its rent to the owner
+    pub fn close_settled_position(ctx: Context<CloseSettledPosition>) -> Result<()> {
+        instructions::close_settled_position::handler(ctx)
+    }
+
+    /// Settle a batch of positions for their owners after resolution (anyone)
+    pub fn crank_settle<'info>(ctx: Context<'_, '_, 'info, 'info, CrankSettle<'info>>) -> Result<()> {
+        instructions::crank_settle::handler(ctx)
+    }
+
+    /// Refund a position's collateral and premium after market cancellation
+    pub fn refund_position(ctx: Context<RefundPosition>) -> Result<()> {
+        instructions::refund_position::handler(ctx)
+    }
+
+    /// Close a position early at the current mark-to-market price
+    pub fn close_position(
+        ctx: Context<ClosePosition>,
+        min_payout: u64,         // minimum received after fees
+        expires_at: Option<i64>, // unix timestamp after which the order is rejected
+    ) -> Result<()> {
+        instructions::close_position::handler(ctx, min_payout, expires_at)
+    }
+
+    /// Reduce an open position by part of its size at mark-to-market
+    pub fn reduce_position(
+        ctx: Context<ReducePosition>,
+        size: u64,
+        min_payout: u64,         // minimum received after fees
+        expires_at: Option<i64>, // unix timestamp after which the order is rejected
+    ) -> Result<()> {
+        instructions::reduce_position::handler(ctx, size, min_payout, expires_at)
+    }
+
+    /// Set the global trading fee (admin)
+    pub fn update_fee_bps(ctx: Context<UpdateFeeBps>, fee_bps: u64) -> Result<()> {
+        instructions::update_fee_bps::handler(ctx, fee_bps)
+    }
+
+    /// Set or clear a market's trading fee override (admin)
+    pub fn update_market_fee_bps(
+        ctx: Context<UpdateMarketFeeBps>,
+        fee_bps: Option<u64>, // None falls back to the global fee
+    ) -> Result<()> {
+        instructions::update_market_fee_bps::handler(ctx, fee_bps)
+    }
+
+    /// Withdraw accrued trading fees to a treasury account (admin)
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_fees::handler(ctx, amount)
+    }
+
+    /// Set the fee paid to crank_settle callers per settled position (admin)
+    pub fn update_crank_fee(ctx: Context<UpdateCrankFee>, crank_fee: u64) -> Result<()> {
+        instructions::update_crank_fee::handler(ctx, crank_fee)
+    }
+
+    /// Set the share of the trading fee paid to referrers in a tier (admin)
+    pub fn set_referral_tier(
+        ctx: Context<SetReferralTier>,
+        tier: u8,
+        share_bps: u64, // share of the fee in basis points
+    ) -> Result<()> {
+        instructions::set_referral_tier::handler(ctx, tier, share_bps)
+    }
+
+    /// Move a referrer to another tier (admin)
+    pub fn set_referrer_tier(ctx: Context<SetReferrerTier>, tier: u8) -> Result<()> {
+        instructions::set_referrer_tier::handler(ctx, tier)
+    }
+
+    /// Register the signer as a referrer that can be credited on open_position
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer::handler(ctx)
+    }
+
+    /// Claim a referrer's accrued share of trading fees
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees::handler(ctx)
+    }
+
+    /// Update optimistic resolution bond and liveness period
+    pub fn update_resolution_params(
+        ctx: Context<UpdateResolutionParams>,
+        resolution_params: state::ResolutionParams,
+    ) -> Result<()> {
+        instructions::update_resolution_params::handler(ctx, resolution_params)
+    }
+
+    /// Propose a market outcome, posting a bond
+    pub fn propose_resolution(
+        ctx: Context<ProposeResolution>,
+        outcome: state::Outcome,
+    ) -> Result<()> {
+        instructions::propose_resolution::handler(ctx, outcome)
+    }
+
+    /// Dispute a proposed outcome within the liveness period, posting a matching bond
+    pub fn dispute_resolution(ctx: Context<DisputeResolution>) -> Result<()> {
+        instructions::dispute_resolution::handler(ctx)
+    }
+
+    /// Finalize an undisputed proposal after the liveness period
+    pub fn finalize_resolution(ctx: Context<FinalizeResolution>) -> Result<()> {
+        instructions::finalize_resolution::handler(ctx)
+    }
+
+    /// Arbitrate a disputed resolution and award both bonds to the winner (resolution keeper)
     pub fn update_market_resolution(
         ctx: Context<UpdateMarketResolution>,
         outcome: state::Outcome,
@@ -70,7 +331,7 @@ pub mod forward {
         instructions::update_market_resolution::handler(ctx, outcome)
     }
 
-    /// Update price oracle with current Polymarket price (keeper function)
+    /// Update price oracle with current Polymarket price (price keeper)
     pub fn update_price_oracle(
         ctx: Context<UpdatePriceOracle>,
         price: u64,
@@ -78,5 +339,14 @@ pub mod forward {
     ) -> Result<()> {
         instructions::update_price_oracle::handler(ctx, price, exponent)
     }
-}
 
+    /// Deposit collateral into a market's pool in exchange for LP shares
+    pub fn deposit_liquidity(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
+        instructions::deposit_liquidity::handler(ctx, amount)
+    }
+
+    /// Burn LP shares and withdraw the corresponding free pool collateral
+    pub fn withdraw_liquidity(ctx: Context<WithdrawLiquidity>, shares: u64) -> Result<()> {
+        instructions::withdraw_liquidity::handler(ctx, shares)
+    }
+}
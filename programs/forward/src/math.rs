@@ -1,74 +1,353 @@
-use crate::state::{CurveParams, Direction, PoolState};
+use anchor_lang::prelude::*;
+use crate::errors::ForwardError;
+use crate::state::{CloseQuote, CurveBreakpoint, CurveKind, CurveParams, Direction, PoolState, Position, Quote, RiskLimits};
 
 /// Basis points constant (10000 = 100%)
 pub const BASIS_POINTS: u64 = 10000;
 
+/// Fixed-point scale used for the transcendental curve shapes
+const CURVE_SCALE: u128 = 1_000_000_000_000;
+
+/// ln(2) at CURVE_SCALE
+const LN_2: u128 = 693_147_180_560;
+
+/// Steepness (k at CURVE_SCALE) below which the closed-form integrals lose
+/// their precision to cancellation and are evaluated as series instead
+const SMALL_STEEPNESS: u128 = CURVE_SCALE / 10;
+
+/// Calculate the exposure ratio e / E_max in basis points, clamped to [-1, 1]
+pub fn calculate_exposure_ratio(
+    net_exposure: i64, // e = Q_long - Q_short
+    curve_params: &CurveParams,
+) -> i64 {
+    let e_max = curve_params.max_exposure as i64;
+    let exposure_ratio = if e_max > 0 {
+        (net_exposure as i128 * BASIS_POINTS as i128 / e_max as i128) as i64
+    } else {
+        0
+    };
+    exposure_ratio.max(-(BASIS_POINTS as i64)).min(BASIS_POINTS as i64)
+}
+
+/// Evaluate the curve shape s(x) for an exposure ratio x in basis points
+///
+/// s is odd (s(-x) = -s(x)), monotonic and maps [0, 1] onto [0, 1], so
+/// α and β keep their meaning as the price shift and premium rate at E_max
+/// whatever the curve family:
+/// - Linear: s(x) = x
+/// - Tanh: s(x) = tanh(k·x) / tanh(k)
+/// - Exponential: s(x) = (e^(k·x) − 1) / (e^k − 1)
+/// - Piecewise: linear interpolation between (0, 0) and the breakpoints
+pub fn curve_shape(
+    exposure_ratio: i64, // x in basis points, within [-BASIS_POINTS, BASIS_POINTS]
+    curve_params: &CurveParams,
+) -> i64 {
+    let x = exposure_ratio.unsigned_abs().min(BASIS_POINTS);
+    let shape = match curve_params.kind {
+        CurveKind::Linear => x,
+        CurveKind::Tanh => {
+            let k = curve_params.steepness as u128 * CURVE_SCALE / BASIS_POINTS as u128;
+            let kx = k * x as u128 / BASIS_POINTS as u128;
+            (tanh_fixed(kx) * BASIS_POINTS as u128)
+                .checked_div(tanh_fixed(k))
+                .map_or(x, |shape| shape as u64)
+        }
+        CurveKind::Exponential => {
+            let k = curve_params.steepness as u128 * CURVE_SCALE / BASIS_POINTS as u128;
+            let kx = k * x as u128 / BASIS_POINTS as u128;
+            ((exp_fixed(kx) - CURVE_SCALE) * BASIS_POINTS as u128)
+                .checked_div(exp_fixed(k) - CURVE_SCALE)
+                .map_or(x, |shape| shape as u64)
+        }
+        CurveKind::Piecewise => {
+            let count = (curve_params.breakpoint_count as usize).min(CurveParams::MAX_BREAKPOINTS);
+            let mut previous = CurveBreakpoint::default();
+            let mut shape = None;
+            for point in &curve_params.breakpoints[..count] {
+                if x <= point.exposure {
+                    let span = point.exposure.saturating_sub(previous.exposure).max(1);
+                    let rise = point.value.saturating_sub(previous.value);
+                    let offset = x.saturating_sub(previous.exposure);
+                    shape = Some(previous.value + (rise as u128 * offset as u128 / span as u128) as u64);
+                    break;
+                }
+                previous = *point;
+            }
+            shape.unwrap_or(previous.value)
+        }
+    };
+    let shape = shape.min(BASIS_POINTS) as i64;
+
+    if exposure_ratio < 0 {
+        -shape
+    } else {
+        shape
+    }
+}
+
+/// e^x for x >= 0 at CURVE_SCALE
+///
+/// Range reduction x = n·ln2 + r with 0 <= r < ln2, then a Taylor series for e^r.
+fn exp_fixed(x: u128) -> u128 {
+    let n = x / LN_2;
+    let r = x - n * LN_2;
+
+    let mut sum = CURVE_SCALE;
+    let mut term = CURVE_SCALE;
+    for i in 1..=20u128 {
+        term = term * r / (i * CURVE_SCALE);
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+
+    sum << n
+}
+
+/// tanh(x) for x >= 0 at CURVE_SCALE
+fn tanh_fixed(x: u128) -> u128 {
+    // tanh(x) = 1 − 2 / (e^(2x) + 1)
+    let e2x = exp_fixed(2 * x);
+    CURVE_SCALE - 2 * CURVE_SCALE * CURVE_SCALE / (e2x + CURVE_SCALE)
+}
+
+/// ln(z) for 1 <= z <= 2 at CURVE_SCALE
+///
+/// Uses ln(z) = 2·atanh((z − 1) / (z + 1)), which converges quickly on this range.
+fn ln_fixed(z: u128) -> u128 {
+    let t = (z - CURVE_SCALE) * CURVE_SCALE / (z + CURVE_SCALE);
+    let t2 = t * t / CURVE_SCALE;
+
+    let mut sum = 0;
+    let mut term = t;
+    for n in 0..30u128 {
+        if term == 0 {
+            break;
+        }
+        sum += term / (2 * n + 1);
+        term = term * t2 / CURVE_SCALE;
+    }
+
+    2 * sum
+}
+
+/// ln(cosh(y)) for y >= 0 at CURVE_SCALE
+fn ln_cosh_fixed(y: u128) -> u128 {
+    // ln(cosh(y)) = y + ln(1 + e^(−2y)) − ln 2
+    let e_neg_2y = CURVE_SCALE * CURVE_SCALE / exp_fixed(2 * y);
+    (y + ln_fixed(CURVE_SCALE + e_neg_2y)).saturating_sub(LN_2)
+}
+
+/// Σ zⁿ · m! / (n + m)! with m = `offset`, for 0 <= z <= 1 at CURVE_SCALE
+fn factorial_series(z: u128, offset: u128) -> u128 {
+    let mut sum = CURVE_SCALE;
+    let mut term = CURVE_SCALE;
+    for n in 1..=20u128 {
+        term = term * z / ((n + offset) * CURVE_SCALE);
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+    sum
+}
+
+/// Σ cₙ z²ⁿ for the given (numerator, denominator) coefficients at CURVE_SCALE
+fn even_series(z: u128, coefficients: &[(i128, i128)]) -> u128 {
+    let z2 = (z * z / CURVE_SCALE) as i128;
+    let mut power = CURVE_SCALE as i128;
+    let mut sum = 0i128;
+    for (numerator, denominator) in coefficients {
+        sum += power * numerator / denominator;
+        power = power * z2 / CURVE_SCALE as i128;
+    }
+    sum as u128
+}
+
+/// Antiderivative S(x) = ∫₀ˣ s(u) du for x >= 0 at CURVE_SCALE
+///
+/// Closed forms per curve family, with k the steepness:
+/// - Linear: x² / 2
+/// - Tanh: ln(cosh(k·x)) / (k·tanh(k))
+/// - Exponential: ((e^(k·x) − 1) / k − x) / (e^k − 1)
+/// - Piecewise: sum of trapezoids between breakpoints
+///
+/// For k < 0.1 the tanh and exponential forms divide a tiny difference by k²,
+/// so they are evaluated as S = x²/2 · f(k·x) / g(k) with f and g as series:
+/// - Tanh: f(y) = ln(cosh(y)) / (y²/2), g(k) = tanh(k) / k
+/// - Exponential: f(y) = (e^y − 1 − y) / (y²/2), g(k) = (e^k − 1) / k
+///
+/// Beyond full exposure the shape is clamped at s(1), so S grows linearly there;
+/// exposures far past E_max can overflow that, which is reported as MathOverflow.
+fn curve_shape_integral(x: u128, curve_params: &CurveParams) -> Result<u128> {
+    if x > CURVE_SCALE {
+        let s_max = curve_shape(BASIS_POINTS as i64, curve_params) as u128 * CURVE_SCALE / BASIS_POINTS as u128;
+        let beyond = (x - CURVE_SCALE)
+            .checked_mul(s_max)
+            .ok_or(ForwardError::MathOverflow)?
+            / CURVE_SCALE;
+        return curve_shape_integral(CURVE_SCALE, curve_params)?
+            .checked_add(beyond)
+            .ok_or(ForwardError::MathOverflow.into());
+    }
+
+    Ok(match curve_params.kind {
+        CurveKind::Linear => x * x / (2 * CURVE_SCALE),
+        CurveKind::Tanh => {
+            let k = curve_params.steepness as u128 * CURVE_SCALE / BASIS_POINTS as u128;
+            let kx = k * x / CURVE_SCALE;
+            if k < SMALL_STEEPNESS {
+                let f = even_series(kx, &[(1, 1), (-1, 6), (2, 45), (-17, 1260)]);
+                let g = even_series(k, &[(1, 1), (-1, 3), (2, 15), (-17, 315)]);
+                return Ok(x * x / (2 * CURVE_SCALE) * f / g);
+            }
+            match (ln_cosh_fixed(kx) * CURVE_SCALE).checked_div(k) {
+                Some(scaled) => (scaled * CURVE_SCALE)
+                    .checked_div(tanh_fixed(k))
+                    .unwrap_or(x * x / (2 * CURVE_SCALE)),
+                None => x * x / (2 * CURVE_SCALE),
+            }
+        }
+        CurveKind::Exponential => {
+            let k = curve_params.steepness as u128 * CURVE_SCALE / BASIS_POINTS as u128;
+            let kx = k * x / CURVE_SCALE;
+            if k < SMALL_STEEPNESS {
+                let f = factorial_series(kx, 2);
+                let g = factorial_series(k, 1);
+                return Ok(x * x / (2 * CURVE_SCALE) * f / g);
+            }
+            match ((exp_fixed(kx) - CURVE_SCALE) * CURVE_SCALE).checked_div(k) {
+                Some(scaled) => (scaled.saturating_sub(x) * CURVE_SCALE)
+                    .checked_div(exp_fixed(k) - CURVE_SCALE)
+                    .unwrap_or(x * x / (2 * CURVE_SCALE)),
+                None => x * x / (2 * CURVE_SCALE),
+            }
+        }
+        CurveKind::Piecewise => {
+            let count = (curve_params.breakpoint_count as usize).min(CurveParams::MAX_BREAKPOINTS);
+            let to_scale = |bps: u64| bps as u128 * CURVE_SCALE / BASIS_POINTS as u128;
+
+            let mut area = 0;
+            let (mut x0, mut s0) = (0u128, 0u128);
+            for point in &curve_params.breakpoints[..count] {
+                let (x1, s1) = (to_scale(point.exposure), to_scale(point.value));
+                if x <= x1 {
+                    let s_at_x = s0 + (s1.saturating_sub(s0) * (x - x0)).checked_div(x1 - x0).unwrap_or(0);
+                    return Ok(area + (s0 + s_at_x) * (x - x0) / (2 * CURVE_SCALE));
+                }
+                area += (s0 + s1) * (x1 - x0) / (2 * CURVE_SCALE);
+                (x0, s0) = (x1, s1);
+            }
+            area + s0 * (x - x0) / CURVE_SCALE
+        }
+    })
+}
+
+/// Signed change in net exposure caused by opening `size` in `direction`
+///
+/// Closing or reducing moves exposure by the negation of this.
+pub fn calculate_exposure_delta(size: u64, direction: Direction) -> i64 {
+    match direction {
+        Direction::Long => size as i64,
+        Direction::Short => -(size as i64),
+    }
+}
+
+/// Average curve shape over the exposure path from e to e + Δe, in basis points
+///
+/// avg = (S(x₁) − S(x₀)) / (x₁ − x₀) with x = e / E_max. Because s is odd, S is
+/// even and S(|x|) is an antiderivative of s on the whole line, so the path may
+/// cross zero. Averaging over the path makes price impact additive: one trade
+/// of size Q costs the same as any sequence of smaller trades summing to Q.
+pub fn calculate_average_curve_shape(
+    net_exposure: i64,   // e before the trade
+    exposure_delta: i64, // Δe caused by the trade
+    curve_params: &CurveParams,
+) -> Result<i64> {
+    let e_max = curve_params.max_exposure as i128;
+    if e_max == 0 {
+        return Ok(0);
+    }
+    if exposure_delta == 0 {
+        return Ok(curve_shape(calculate_exposure_ratio(net_exposure, curve_params), curve_params));
+    }
+
+    let x0 = net_exposure as i128 * CURVE_SCALE as i128 / e_max;
+    let x1 = (net_exposure as i128 + exposure_delta as i128) * CURVE_SCALE as i128 / e_max;
+    if x0 == x1 {
+        return Ok(curve_shape(calculate_exposure_ratio(net_exposure, curve_params), curve_params));
+    }
+
+    let integral = |x: i128| -> Result<i128> {
+        i128::try_from(curve_shape_integral(x.unsigned_abs(), curve_params)?)
+            .map_err(|_| ForwardError::MathOverflow.into())
+    };
+    let average = integral(x1)?
+        .checked_sub(integral(x0)?)
+        .and_then(|area| area.checked_mul(BASIS_POINTS as i128))
+        .ok_or(ForwardError::MathOverflow)?
+        / (x1 - x0);
+
+    Ok(average.max(-(BASIS_POINTS as i128)).min(BASIS_POINTS as i128) as i64)
+}
+
 /// Calculate forward price K based on pAMM curve
 /// 
-/// Formula: K = p + α * (e / E_max)
+/// Formula: K = p + α * avg s(x) over x from e / E_max to (e + Δe) / E_max
 /// Where:
 /// - p = Polymarket price (0 to 1, stored as basis points)
 /// - e = net exposure = Q_long - Q_short
+/// - Δe = change in net exposure caused by the trade
 /// - E_max = maximum allowed exposure
 /// - α = curve slope parameter
+/// - s = curve shape selected by `curve_params.kind` (see `curve_shape`)
 pub fn calculate_forward_price(
     polymarket_price: u64, // p in basis points (e.g., 5000 = 0.5)
     pool_state: &PoolState,
     curve_params: &CurveParams,
-) -> u64 {
+    exposure_delta: i64,   // Δe
+) -> Result<u64> {
     let p = polymarket_price as i64;
-    let e = pool_state.net_exposure();
-    let e_max = curve_params.max_exposure as i64;
     let alpha = curve_params.alpha as i64;
 
-    // Calculate exposure ratio: e / E_max (clamped to [-1, 1])
-    let exposure_ratio = if e_max > 0 {
-        (e * BASIS_POINTS as i64) / e_max
-    } else {
-        0
-    };
-    let exposure_ratio = exposure_ratio.max(-(BASIS_POINTS as i64)).min(BASIS_POINTS as i64);
+    // Curve shape averaged over the trade's exposure path
+    let shape = calculate_average_curve_shape(pool_state.net_exposure(), exposure_delta, curve_params)?;
 
-    // K = p + α * (exposure_ratio / BASIS_POINTS)
-    let k = p + (alpha * exposure_ratio) / (BASIS_POINTS as i64);
+    // K = p + α * (shape / BASIS_POINTS)
+    let k = p + (alpha * shape) / (BASIS_POINTS as i64);
 
     // Clamp K between min_price and max_price
     let k = k.max(curve_params.min_price as i64).min(curve_params.max_price as i64);
 
-    k as u64
+    Ok(k as u64)
 }
 
 /// Calculate premium rate based on exposure
 /// 
-/// Formula: premium_rate = β * (e / E_max)
+/// Formula: premium_rate = β * avg s(x), averaged over the same exposure path as K
 /// For long: premium = premium_rate * Q
 /// For short: premium = -premium_rate * Q
 pub fn calculate_premium_rate(
     pool_state: &PoolState,
     curve_params: &CurveParams,
     direction: Direction,
-) -> i64 {
-    let e = pool_state.net_exposure();
-    let e_max = curve_params.max_exposure as i64;
+    exposure_delta: i64, // Δe
+) -> Result<i64> {
     let beta = curve_params.beta as i64;
 
-    // Calculate exposure ratio
-    let exposure_ratio = if e_max > 0 {
-        (e * BASIS_POINTS as i64) / e_max
-    } else {
-        0
-    };
-    let exposure_ratio = exposure_ratio.max(-(BASIS_POINTS as i64)).min(BASIS_POINTS as i64);
+    // Curve shape averaged over the trade's exposure path
+    let shape = calculate_average_curve_shape(pool_state.net_exposure(), exposure_delta, curve_params)?;
 
-    // premium_rate = β * exposure_ratio
-    let premium_rate = (beta * exposure_ratio) / (BASIS_POINTS as i64);
+    // premium_rate = β * shape
+    let premium_rate = (beta * shape) / (BASIS_POINTS as i64);
 
     // For long: positive premium_rate means user pays
     // For short: negative premium_rate means user receives
-    match direction {
+    Ok(match direction {
         Direction::Long => premium_rate,
         Direction::Short => -premium_rate,
-    }
+    })
 }
 
 /// Calculate total premium for a position
@@ -81,6 +360,21 @@ pub fn calculate_premium(
     (premium_rate * size as i64) / (BASIS_POINTS as i64)
 }
 
+/// Calculate the trading fee on a trade's notional
+///
+/// fee = Q * K * fee_bps, rounded up so small trades cannot round the fee
+/// away. Notional is Q * K for both directions.
+pub fn calculate_fee(
+    forward_price: u64, // K in basis points
+    size: u64,          // Q
+    fee_bps: u64,
+) -> Result<u64> {
+    let denominator = BASIS_POINTS as u128 * BASIS_POINTS as u128;
+    let fee = (size as u128 * forward_price as u128 * fee_bps as u128)
+        .div_ceil(denominator);
+    u64::try_from(fee).map_err(|_| ForwardError::MathOverflow.into())
+}
+
 /// Calculate required collateral for a position
 /// 
 /// Long collateral: K * Q
@@ -103,11 +397,23 @@ pub fn calculate_collateral(
     }
 }
 
+/// Calculate mark-to-market value of a position at a given forward price
+///
+/// A long is worth K * Q and a short (1 - K) * Q, i.e. the collateral a new
+/// position of the same direction would lock at that price.
+pub fn calculate_mark_to_market_value(
+    forward_price: u64, // K in basis points
+    size: u64,          // Q
+    direction: Direction,
+) -> u64 {
+    calculate_collateral(forward_price, size, direction)
+}
+
 /// Calculate maximum allowed size for a new position
 pub fn calculate_max_size(
     pool_state: &PoolState,
     _curve_params: &CurveParams,
-    risk_limits: &crate::state::RiskLimits,
+    risk_limits: &RiskLimits,
     direction: Direction,
 ) -> u64 {
     let current_exposure = match direction {
@@ -135,25 +441,487 @@ pub fn calculate_max_size(
     max_by_total.min(max_by_share)
 }
 
+/// Largest size up to `max_size` the pool can take in `direction` right now
+///
+/// `open_position` rejects a trade whose pool collateral isn't backed by LP
+/// capital after the premium, or that leaves a vault holding `vault_amount`
+/// unable to cover either outcome. Both depend on K along the trade's path,
+/// so the bound is found by bisection over full quotes run through the same
+/// `PoolState` checks.
+pub fn calculate_fillable_size(
+    polymarket_price: u64,
+    pool_state: &PoolState,
+    curve_params: &CurveParams,
+    risk_limits: &RiskLimits,
+    direction: Direction,
+    vault_amount: u64,
+    max_size: u64,
+) -> u64 {
+    let fits = |size: u64| {
+        let Ok(quote) = quote_open(polymarket_price, pool_state, curve_params, risk_limits, 0, direction, size) else {
+            return false;
+        };
+        // The vault receives the user's collateral and a positive premium, and
+        // pays out a negative one
+        let vault_after = vault_amount as i128 + quote.user_collateral as i128 + quote.premium as i128;
+        let mut pool_after = pool_state.clone();
+        pool_after
+            .open(direction, size, quote.pool_collateral, quote.premium)
+            .is_ok()
+            && u64::try_from(vault_after).is_ok_and(|vault_after| pool_after.check_solvency(vault_after).is_ok())
+    };
+
+    let (mut low, mut high) = (0, max_size);
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if fits(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low
+}
+
+/// Price opening `size` in `direction` against the current pool
+///
+/// Shared by `open_position`, `increase_position` and `quote_open` so the
+/// preview is exactly what the trade will execute at.
+pub fn quote_open(
+    polymarket_price: u64,
+    pool_state: &PoolState,
+    curve_params: &CurveParams,
+    risk_limits: &RiskLimits,
+    fee_bps: u64,
+    direction: Direction,
+    size: u64,
+) -> Result<Quote> {
+    // K and the premium rate are averaged over the exposure path this trade walks
+    let exposure_delta = calculate_exposure_delta(size, direction);
+    let forward_price = calculate_forward_price(polymarket_price, pool_state, curve_params, exposure_delta)?;
+    let premium_rate = calculate_premium_rate(pool_state, curve_params, direction, exposure_delta)?;
+    let premium = calculate_premium(premium_rate, size);
+
+    let user_collateral = calculate_collateral(forward_price, size, direction);
+
+    // Pool collateral (opposite side) is derived as Q - user collateral so that
+    // settlement and early closure, which release size - collateral_locked,
+    // give back exactly what was locked
+    let pool_collateral = size
+        .checked_sub(user_collateral)
+        .ok_or(ForwardError::MathOverflow)?;
+
+    let max_size = calculate_max_size(pool_state, curve_params, risk_limits, direction);
+    let fee = calculate_fee(forward_price, size, fee_bps)?;
+
+    Ok(Quote {
+        forward_price,
+        premium,
+        user_collateral,
+        pool_collateral,
+        max_size,
+        fee,
+    })
+}
+
+/// Price closing `size` of `position` against the current pool
+///
+/// Shared by `close_position`, `reduce_position` and `quote_close`. The exited
+/// size releases its proportional share of the position's collateral.
+pub fn quote_close(
+    polymarket_price: u64,
+    pool_state: &PoolState,
+    curve_params: &CurveParams,
+    position: &Position,
+    fee_bps: u64,
+    size: u64,
+) -> Result<CloseQuote> {
+    require!(
+        size > 0 && size <= position.size,
+        ForwardError::PositionSizeExceedsLimit
+    );
+
+    let released_collateral = (position.collateral_locked as u128 * size as u128
+        / position.size as u128) as u64;
+    let pool_collateral = size
+        .checked_sub(released_collateral)
+        .ok_or(ForwardError::MathOverflow)?;
+
+    // Exit K is averaged over the exposure path the exit unwinds
+    let exit_price = calculate_forward_price(
+        polymarket_price,
+        pool_state,
+        curve_params,
+        -calculate_exposure_delta(size, position.direction),
+    )?;
+    let payout = calculate_mark_to_market_value(exit_price, size, position.direction);
+    // The fee comes out of the payout and is capped at it: the released
+    // collateral belongs to LPs, who must not fund the protocol's fee
+    let fee = calculate_fee(exit_price, size, fee_bps)?.min(payout);
+    let user_payout = payout - fee;
+
+    Ok(CloseQuote {
+        exit_price,
+        payout,
+        fee,
+        user_payout,
+        released_collateral,
+        pool_collateral,
+    })
+}
+
 /// Calculate settlement payout for a position
 /// 
-/// If outcome = YES:
-///   Long receives: Q (total collateral)
-///   Short receives: 0
-/// 
-/// If outcome = NO:
-///   Long receives: 0
-///   Short receives: Q (total collateral)
+/// With f = YES payout fraction (1 for YES, 0 for NO, anything in between
+/// for a split resolution):
+///   Long receives: Q * f
+///   Short receives: Q * (1 - f)
 pub fn calculate_settlement_payout(
     size: u64,
     direction: Direction,
     outcome: crate::state::Outcome,
 ) -> u64 {
-    match (direction, outcome) {
-        (Direction::Long, crate::state::Outcome::Yes) => size,
-        (Direction::Long, crate::state::Outcome::No) => 0,
-        (Direction::Short, crate::state::Outcome::Yes) => 0,
-        (Direction::Short, crate::state::Outcome::No) => size,
+    let long_payout = (size as u128 * outcome.payout_fraction() as u128 / BASIS_POINTS as u128) as u64;
+    match direction {
+        Direction::Long => long_payout,
+        Direction::Short => size - long_payout,
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(kind: CurveKind, steepness: u64) -> CurveParams {
+        CurveParams {
+            alpha: 1000,
+            beta: 500,
+            max_exposure: 1_000_000,
+            min_price: 0,
+            max_price: BASIS_POINTS,
+            kind,
+            steepness,
+            breakpoint_count: 0,
+            breakpoints: [CurveBreakpoint::default(); CurveParams::MAX_BREAKPOINTS],
+        }
+    }
+
+    fn piecewise(points: &[(u64, u64)]) -> CurveParams {
+        let mut params = curve(CurveKind::Piecewise, 0);
+        for (slot, &(exposure, value)) in params.breakpoints.iter_mut().zip(points) {
+            *slot = CurveBreakpoint { exposure, value };
+        }
+        params.breakpoint_count = points.len() as u8;
+        params
+    }
+
+    /// Every curve family, at the steepness extremes the program accepts
+    fn all_curves() -> Vec<CurveParams> {
+        vec![
+            curve(CurveKind::Linear, 0),
+            curve(CurveKind::Tanh, 1),
+            curve(CurveKind::Tanh, 30_000),
+            curve(CurveKind::Tanh, CurveParams::MAX_STEEPNESS),
+            curve(CurveKind::Exponential, 1),
+            curve(CurveKind::Exponential, 30_000),
+            curve(CurveKind::Exponential, CurveParams::MAX_STEEPNESS),
+            piecewise(&[(5000, 2000), (10_000, 10_000)]),
+        ]
+    }
+
+    fn to_fixed(value: f64) -> u128 {
+        (value * CURVE_SCALE as f64).round() as u128
+    }
+
+    fn from_fixed(value: u128) -> f64 {
+        value as f64 / CURVE_SCALE as f64
+    }
+
+    #[test]
+    fn exp_fixed_matches_f64() {
+        for i in 0..=400 {
+            let x = i as f64 * 0.05;
+            let expected = x.exp();
+            let actual = from_fixed(exp_fixed(to_fixed(x)));
+            assert!(
+                (actual - expected).abs() <= expected * 1e-9,
+                "exp({}) = {}, expected {}",
+                x,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn tanh_fixed_matches_f64() {
+        for i in 0..=200 {
+            let x = i as f64 * 0.05;
+            let actual = from_fixed(tanh_fixed(to_fixed(x)));
+            assert!(
+                (actual - x.tanh()).abs() <= 1e-9,
+                "tanh({}) = {}, expected {}",
+                x,
+                actual,
+                x.tanh()
+            );
+        }
+    }
+
+    #[test]
+    fn curve_shape_is_bounded_monotonic_and_odd() {
+        for params in all_curves() {
+            assert_eq!(curve_shape(0, &params), 0);
+            let mut previous = 0;
+            for x in (0..=BASIS_POINTS as i64).step_by(50) {
+                let shape = curve_shape(x, &params);
+                assert!(shape >= previous, "{:?} decreases at x={}", params.kind, x);
+                assert!(shape <= BASIS_POINTS as i64, "{:?} exceeds 1 at x={}", params.kind, x);
+                assert_eq!(curve_shape(-x, &params), -shape);
+                previous = shape;
+            }
+            // s(1) = 1 (up to fixed-point rounding), so α and β keep their meaning
+            let full = curve_shape(BASIS_POINTS as i64, &params);
+            assert!(full >= BASIS_POINTS as i64 - 1, "{:?}: s(1) = {}", params.kind, full);
+        }
+    }
+
+    #[test]
+    fn curve_shape_matches_f64() {
+        for steepness in [1u64, 5000, 30_000, CurveParams::MAX_STEEPNESS] {
+            let k = steepness as f64 / BASIS_POINTS as f64;
+            let tanh = curve(CurveKind::Tanh, steepness);
+            let exponential = curve(CurveKind::Exponential, steepness);
+            for x in (0..=BASIS_POINTS as i64).step_by(250) {
+                let u = x as f64 / BASIS_POINTS as f64;
+                let expected_tanh = (k * u).tanh() / k.tanh() * BASIS_POINTS as f64;
+                let expected_exp = ((k * u).exp() - 1.0) / (k.exp() - 1.0) * BASIS_POINTS as f64;
+                assert!((curve_shape(x, &tanh) as f64 - expected_tanh).abs() <= 1.0);
+                assert!((curve_shape(x, &exponential) as f64 - expected_exp).abs() <= 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn piecewise_interpolates_between_breakpoints() {
+        let params = piecewise(&[(2000, 500), (5000, 2000), (10_000, 10_000)]);
+        assert_eq!(curve_shape(1000, &params), 250);
+        assert_eq!(curve_shape(2000, &params), 500);
+        assert_eq!(curve_shape(3500, &params), 1250);
+        assert_eq!(curve_shape(5000, &params), 2000);
+        assert_eq!(curve_shape(7500, &params), 6000);
+        assert_eq!(curve_shape(10_000, &params), 10_000);
+        assert_eq!(curve_shape(-7500, &params), -6000);
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn piecewise_validation_rejects_bad_breakpoints() {
+        // Must end at full exposure
+        assert!(piecewise(&[(5000, 2000)]).validate().is_err());
+        // Exposure must strictly increase
+        assert!(piecewise(&[(5000, 2000), (5000, 3000), (10_000, 10_000)]).validate().is_err());
+        // Values must not decrease
+        assert!(piecewise(&[(5000, 6000), (10_000, 5000)]).validate().is_err());
+        // Values are capped at 1
+        assert!(piecewise(&[(10_000, 10_001)]).validate().is_err());
+    }
+
+    fn pool(net_exposure: i64) -> PoolState {
+        PoolState {
+            market: Pubkey::default(),
+            total_long_exposure: net_exposure.max(0) as u64 + 1_000_000,
+            total_short_exposure: (-net_exposure).max(0) as u64 + 1_000_000,
+            pool_collateral: 0,
+            position_counter: 0,
+            lp_nav: 0,
+            bump: 0,
+        }
+    }
+
+    /// f64 reference for S(x) = ∫₀ˣ s(u) du with x in [0, 1]
+    fn reference_integral(x: f64, params: &CurveParams) -> f64 {
+        let k = params.steepness as f64 / BASIS_POINTS as f64;
+        match params.kind {
+            CurveKind::Linear => x * x / 2.0,
+            // ln(cosh(y)) = ln(1 + 2·sinh²(y/2)), which keeps precision for small y
+            CurveKind::Tanh => (2.0 * (k * x / 2.0).sinh().powi(2)).ln_1p() / (k * k.tanh()),
+            CurveKind::Exponential => ((k * x).exp_m1() / k - x) / k.exp_m1(),
+            CurveKind::Piecewise => {
+                // Sum of trapezoids up to x
+                let mut area = 0.0;
+                let (mut x0, mut s0) = (0.0, 0.0);
+                for point in &params.breakpoints[..params.breakpoint_count as usize] {
+                    let x1 = point.exposure as f64 / BASIS_POINTS as f64;
+                    let s1 = point.value as f64 / BASIS_POINTS as f64;
+                    if x <= x1 {
+                        let s_at_x = s0 + (s1 - s0) * (x - x0) / (x1 - x0);
+                        return area + (s0 + s_at_x) * (x - x0) / 2.0;
+                    }
+                    area += (s0 + s1) * (x1 - x0) / 2.0;
+                    (x0, s0) = (x1, s1);
+                }
+                area
+            }
+        }
+    }
+
+    #[test]
+    fn ln_fixed_matches_f64() {
+        for i in 0..=200 {
+            let z = 1.0 + i as f64 / 200.0;
+            let actual = from_fixed(ln_fixed(to_fixed(z)));
+            assert!(
+                (actual - z.ln()).abs() <= 1e-9,
+                "ln({}) = {}, expected {}",
+                z,
+                actual,
+                z.ln()
+            );
+        }
+    }
+
+    #[test]
+    fn ln_cosh_fixed_matches_f64() {
+        for i in 0..=200 {
+            let y = i as f64 * 0.05;
+            let expected = y.cosh().ln();
+            let actual = from_fixed(ln_cosh_fixed(to_fixed(y)));
+            assert!(
+                (actual - expected).abs() <= 1e-9,
+                "ln(cosh({})) = {}, expected {}",
+                y,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn curve_shape_integral_matches_closed_forms() {
+        for params in all_curves() {
+            for i in 0..=20 {
+                let x = i as f64 / 20.0;
+                let expected = reference_integral(x, &params);
+                let actual = from_fixed(curve_shape_integral(to_fixed(x), &params).unwrap());
+                assert!(
+                    (actual - expected).abs() <= 1e-9,
+                    "{:?} (k={}): S({}) = {}, expected {}",
+                    params.kind,
+                    params.steepness,
+                    x,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn curve_shape_integral_is_linear_beyond_full_exposure() {
+        for params in all_curves() {
+            let at_full = curve_shape_integral(CURVE_SCALE, &params).unwrap();
+            let s_max = curve_shape(BASIS_POINTS as i64, &params) as u128 * CURVE_SCALE / BASIS_POINTS as u128;
+            let beyond = curve_shape_integral(3 * CURVE_SCALE, &params).unwrap();
+            assert_eq!(beyond, at_full + 2 * s_max);
+        }
+    }
+
+    #[test]
+    fn curve_shape_integral_reports_overflow() {
+        let params = curve(CurveKind::Linear, 0);
+        assert!(curve_shape_integral(u128::MAX / 2, &params).is_err());
+
+        // An exposure far past a tiny E_max surfaces as an error, not a panic
+        let tiny = CurveParams { max_exposure: 1, ..params };
+        assert!(calculate_average_curve_shape(i64::MAX / 2, 1, &tiny).is_err());
+    }
+
+    #[test]
+    fn average_curve_shape_is_bounded() {
+        for params in all_curves() {
+            for (e, delta) in [(0, 1), (0, 500_000), (-300_000, 600_000), (900_000, 400_000), (-2_000_000, -1)] {
+                let average = calculate_average_curve_shape(e, delta, &params).unwrap();
+                assert!(average.abs() <= BASIS_POINTS as i64);
+                // The average lies between the shape at both ends of the path
+                let lo = curve_shape(calculate_exposure_ratio(e, &params), &params);
+                let hi = curve_shape(calculate_exposure_ratio(e + delta, &params), &params);
+                assert!(average >= lo.min(hi) - 1 && average <= lo.max(hi) + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn split_trade_costs_the_same_as_one_trade() {
+        let risk_limits = RiskLimits {
+            max_total_exposure: u64::MAX / 4,
+            max_long_share: BASIS_POINTS,
+            max_short_share: BASIS_POINTS,
+        };
+        for params in all_curves() {
+            for (net_exposure, direction) in [
+                (0, Direction::Long),
+                (-400_000, Direction::Long), // crosses zero
+                (250_000, Direction::Short),
+                (700_000, Direction::Long),  // runs past E_max
+            ] {
+                let (first, second) = (150_000u64, 350_000u64);
+                let whole = quote_open(5000, &pool(net_exposure), &params, &risk_limits, 0, direction, first + second).unwrap();
+                let part1 = quote_open(5000, &pool(net_exposure), &params, &risk_limits, 0, direction, first).unwrap();
+                let after = net_exposure + calculate_exposure_delta(first, direction);
+                let part2 = quote_open(5000, &pool(after), &params, &risk_limits, 0, direction, second).unwrap();
+
+                // Per-trade rounding is at most one basis point of the size
+                let tolerance = (first + second) as i64 / BASIS_POINTS as i64 * 2;
+                let premium_diff = whole.premium - (part1.premium + part2.premium);
+                assert!(
+                    premium_diff.abs() <= tolerance,
+                    "{:?}: premium {} vs {} + {}",
+                    params.kind,
+                    whole.premium,
+                    part1.premium,
+                    part2.premium
+                );
+                let collateral_diff = whole.user_collateral as i64
+                    - (part1.user_collateral + part2.user_collateral) as i64;
+                assert!(
+                    collateral_diff.abs() <= tolerance,
+                    "{:?}: collateral {} vs {} + {}",
+                    params.kind,
+                    whole.user_collateral,
+                    part1.user_collateral,
+                    part2.user_collateral
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn losing_close_fee_is_capped_at_the_payout() {
+        // A short exiting at K = 0.97 is worth 3% of its size, less than a 10% fee on Q·K
+        let params = curve(CurveKind::Linear, 0);
+        let position = Position {
+            owner: Pubkey::default(),
+            market: Pubkey::default(),
+            direction: Direction::Short,
+            size: 1_000_000,
+            forward_price: 5000,
+            collateral_locked: 500_000,
+            premium_paid: 0,
+            fee_paid: 0,
+            status: crate::state::PositionStatus::Open,
+            bump: 0,
+        };
+        let quote = quote_close(9200, &pool(0), &params, &position, 1000, position.size).unwrap();
+        assert_eq!(quote.exit_price, 9700);
+        assert_eq!(quote.payout, 30_000);
+        assert!(calculate_fee(9700, position.size, 1000).unwrap() > quote.payout);
+        assert_eq!(quote.fee, quote.payout);
+        assert_eq!(quote.user_payout, 0);
+        assert_eq!(quote.released_collateral, position.collateral_locked);
+
+        // A winning exit has the fee deducted from the payout
+        let quote = quote_close(1000, &pool(0), &params, &position, 100, position.size).unwrap();
+        assert_eq!(quote.user_payout, quote.payout - quote.fee);
+    }
+}
@@ -17,48 +17,78 @@ impl PriceOracle {
 }
 
 /// Oracle resolution feed account structure
+///
+/// Resolution is optimistic: a proposer posts an outcome with a bond, which
+/// becomes final after the liveness period unless someone disputes it by
+/// posting a matching bond, in which case the resolution keeper arbitrates.
 #[account]
 pub struct ResolutionOracle {
-    pub outcome: Option<u8>, // 1 = YES, 0 = NO, None = not resolved
+    pub payout_fraction: Option<u64>, // YES payout in basis points (10000 = YES, 0 = NO), None = not resolved
     pub resolved_at: Option<i64>,
+    pub status: ResolutionStatus,
+    pub proposer: Pubkey,
+    pub proposed_payout_fraction: u64,
+    pub dispute_deadline: i64,
+    pub disputer: Pubkey,
+    pub bond: u64, // Bond posted by each side, snapshot at proposal time
 }
 
 impl ResolutionOracle {
-    pub const LEN: usize = 8 + 1 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + 1 + 8 + 1 + 8 + 1 + 32 + 8 + 8 + 32 + 8;
 }
 
+/// Optimistic resolution lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum ResolutionStatus {
+    Unproposed,
+    Proposed,
+    Disputed,
+    Resolved,
+    Cancelled, // Disputed proposal voided by cancel_market; bonds returned
+}
+
+/// Oldest oracle price (seconds) that `read_price` accepts
+pub const MAX_PRICE_AGE: i64 = 300;
+
 /// Read price from oracle account
 pub fn read_price(oracle_account: &Account<PriceOracle>) -> Result<u64> {
+    let clock = Clock::get()?;
+    validate_price(oracle_account, clock.unix_timestamp)
+}
+
+/// Validate an oracle price as of `now`
+///
+/// Split out of `read_price` so off-chain clients can apply the same checks.
+pub fn validate_price(oracle: &PriceOracle, now: i64) -> Result<u64> {
     // Validate price is within valid range [0, BASIS_POINTS]
     require!(
-        oracle_account.price <= BASIS_POINTS,
+        oracle.price <= BASIS_POINTS,
         ForwardError::InvalidOracleData
     );
     
-    // Validate timestamp freshness (price must be < 5 minutes old and not in future)
-    let clock = Clock::get()?;
-    let max_age: i64 = 300; // 5 minutes in seconds
+    // Validate timestamp freshness (price must be at most MAX_PRICE_AGE old and not in future)
     
     // Reject if timestamp is in the future
     require!(
-        oracle_account.timestamp <= clock.unix_timestamp,
+        oracle.timestamp <= now,
         ForwardError::InvalidOracleData
     );
     
     // Reject if timestamp is too old
     require!(
-        clock.unix_timestamp.saturating_sub(oracle_account.timestamp) <= max_age,
+        now.saturating_sub(oracle.timestamp) <= MAX_PRICE_AGE,
         ForwardError::InvalidOracleData
     );
     
-    Ok(oracle_account.price)
+    Ok(oracle.price)
 }
 
 /// Read resolution from oracle account
 pub fn read_resolution(oracle_account: &Account<ResolutionOracle>) -> Result<Option<crate::state::Outcome>> {
-    match oracle_account.outcome {
-        Some(1) => Ok(Some(crate::state::Outcome::Yes)),
-        Some(0) => Ok(Some(crate::state::Outcome::No)),
+    match oracle_account.payout_fraction {
+        Some(fraction) if fraction <= BASIS_POINTS => {
+            Ok(Some(crate::state::Outcome::from_payout_fraction(fraction)))
+        }
         Some(_) => Err(ForwardError::InvalidOracleData.into()),
         None => Ok(None),
     }
@@ -1,16 +1,66 @@
 use anchor_lang::prelude::*;
+use crate::errors::ForwardError;
+use crate::math::{self, BASIS_POINTS};
 
 /// Global configuration for the platform
 #[account]
 pub struct GlobalConfig {
     pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>, // Proposed admin awaiting acceptance
+    pub price_keeper: Pubkey,      // Pushes oracle prices
+    pub resolution_keeper: Pubkey, // Arbitrates disputed resolutions
+    pub risk_manager: Pubkey,      // Curve parameters and trading halts
     pub collateral_mint: Pubkey,
     pub curve_params: CurveParams,
+    pub pending_curve_params: Option<PendingCurveParams>, // Queued curve change
+    pub curve_update_delay: i64,                          // Timelock for curve changes (seconds)
+    pub pending_curve_update_delay: Option<PendingCurveUpdateDelay>, // Queued reduction of the timelock
+    pub resolution_params: ResolutionParams,
+    pub fee_bps: u64, // Trading fee on notional at open and close (basis points)
+    pub referral_tiers: [u64; GlobalConfig::MAX_REFERRAL_TIERS], // Referrer share of the fee per tier (basis points)
+    pub crank_fee: u64, // Paid from the fee vault to crank_settle callers per settled position
     pub bump: u8,
 }
 
 impl GlobalConfig {
-    pub const LEN: usize = 8 + 32 + 32 + CurveParams::LEN + 1;
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 32 + 32 + 32 + 32 + CurveParams::LEN + 1 + PendingCurveParams::LEN + 8 + 1 + PendingCurveUpdateDelay::LEN + ResolutionParams::LEN + 8 + 8 * Self::MAX_REFERRAL_TIERS + 8 + 1;
+    pub const MAX_FEE_BPS: u64 = 1000; // 10%
+    pub const MAX_REFERRAL_TIERS: usize = 4;
+    pub const MAX_CURVE_UPDATE_DELAY: i64 = 30 * 24 * 60 * 60; // 30 days
+    pub const CRANK_FEE_SHARE_BPS: u64 = 5000; // Crank fee cap as a share of the position's trading fee
+
+    /// Referrer's share of `fee` at `tier`
+    pub fn referral_share(&self, tier: u8, fee: u64) -> Result<u64> {
+        let share_bps = self
+            .referral_tiers
+            .get(tier as usize)
+            .ok_or(ForwardError::InvalidReferralTier)?;
+        Ok((fee as u128 * *share_bps as u128 / BASIS_POINTS as u128) as u64)
+    }
+
+    /// Promote queued curve parameters once their activation time has passed
+    pub fn apply_pending_curve_params(&mut self, now: i64) {
+        if let Some(curve_params) = PendingCurveParams::take_active(&mut self.pending_curve_params, now) {
+            self.curve_params = curve_params;
+        }
+    }
+
+    /// Curve timelock in effect at `now`, including a queued reduction whose
+    /// own delay has elapsed
+    pub fn active_curve_update_delay(&self, now: i64) -> i64 {
+        match &self.pending_curve_update_delay {
+            Some(queued) if now >= queued.activates_at => queued.curve_update_delay,
+            _ => self.curve_update_delay,
+        }
+    }
+}
+
+/// Operational roles the admin can delegate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Role {
+    PriceKeeper,
+    ResolutionKeeper,
+    RiskManager,
 }
 
 /// Market configuration for each Polymarket market
@@ -24,12 +74,44 @@ pub struct MarketConfig {
     pub status: MarketStatus,
     pub pool_state: Pubkey,
     pub collateral_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub curve_params: Option<CurveParams>,                // Overrides GlobalConfig.curve_params
+    pub pending_curve_params: Option<PendingMarketCurveParams>, // Queued override change
+    pub fee_bps: Option<u64>,                             // Overrides GlobalConfig.fee_bps
     pub bump: u8,
 }
 
 impl MarketConfig {
     pub const MAX_MARKET_ID_LEN: usize = 256;
-    pub const LEN: usize = 8 + 4 + Self::MAX_MARKET_ID_LEN + 8 + 32 + 32 + RiskLimits::LEN + 1 + 32 + 32 + 1;
+    pub const LEN: usize = 8 + 4 + Self::MAX_MARKET_ID_LEN + 8 + 32 + 32 + RiskLimits::LEN + 1 + 32 + 32 + 32 + 1 + CurveParams::LEN + 1 + PendingMarketCurveParams::LEN + 1 + 8 + 1;
+
+    /// Trading fee in effect for this market: the override if set, else the global fee
+    pub fn effective_fee_bps(&self, global_config: &GlobalConfig) -> u64 {
+        self.fee_bps.unwrap_or(global_config.fee_bps)
+    }
+
+    /// Curve parameters the next trade would use at `now`, including queued
+    /// changes whose timelock has elapsed but have not been applied yet
+    ///
+    /// Trades price with this instead of applying the queued changes, so they
+    /// don't need to write the global or market config.
+    pub fn active_curve_params(&self, global_config: &GlobalConfig, now: i64) -> CurveParams {
+        let market_override = match PendingMarketCurveParams::active(&self.pending_curve_params, now) {
+            Some(queued) => queued.as_ref(),
+            None => self.curve_params.as_ref(),
+        };
+        market_override
+            .or(PendingCurveParams::active(&global_config.pending_curve_params, now))
+            .unwrap_or(&global_config.curve_params)
+            .clone()
+    }
+
+    /// Promote a queued override change (set or removal) once its activation time has passed
+    pub fn apply_pending_curve_params(&mut self, now: i64) {
+        if let Some(curve_params) = PendingMarketCurveParams::take_active(&mut self.pending_curve_params, now) {
+            self.curve_params = curve_params;
+        }
+    }
 }
 
 /// Pool state tracking exposure for a market
@@ -40,15 +122,208 @@ pub struct PoolState {
     pub total_short_exposure: u64, // Q_short
     pub pool_collateral: u64,      // Total pool collateral locked
     pub position_counter: u64,     // Counter for unique position IDs
+    pub lp_nav: u64,               // LP net asset value (incl. locked pool collateral and premiums)
     pub bump: u8,
 }
 
 impl PoolState {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Shares per base unit of NAV a wiped-out pool is repriced at
+    pub const WIPED_OUT_SHARES_PER_UNIT: u64 = 1_000_000;
 
     pub fn net_exposure(&self) -> i64 {
         self.total_long_exposure as i64 - self.total_short_exposure as i64
     }
+
+    /// Payout owed to open positions if the market resolves to `outcome`
+    ///
+    /// Every long pays out its size on YES and every short on NO, so the
+    /// exposure counters are exactly the per-outcome liabilities; split
+    /// outcomes pay the weighted mix of both.
+    pub fn liability(&self, outcome: Outcome) -> u64 {
+        math::calculate_settlement_payout(self.total_long_exposure, Direction::Long, outcome)
+            .saturating_add(math::calculate_settlement_payout(
+                self.total_short_exposure,
+                Direction::Short,
+                outcome,
+            ))
+    }
+
+    /// Worst-case payout owed to open positions across all outcomes
+    ///
+    /// Payouts are linear in the YES fraction, so the worst case is YES or NO.
+    pub fn max_liability(&self) -> u64 {
+        self.liability(Outcome::Yes).max(self.liability(Outcome::No))
+    }
+
+    /// LP capital not currently locked against open positions
+    pub fn free_liquidity(&self) -> u64 {
+        self.lp_nav.saturating_sub(self.pool_collateral)
+    }
+
+    /// LP shares minted for depositing `amount` when `supply` shares exist
+    ///
+    /// Shares are priced at `lp_nav / supply`, and the first deposit into an
+    /// empty pool sets 1:1. If the LPs were wiped out (`supply > 0` with no
+    /// NAV left) the outstanding shares can't be burned, so the pool is
+    /// repriced against a NAV floor of `supply / WIPED_OUT_SHARES_PER_UNIT`
+    /// (at least one base unit): the old shares keep only that floor's
+    /// worth of the new deposit.
+    pub fn shares_for_deposit(&self, amount: u64, supply: u64) -> Result<u64> {
+        if supply == 0 {
+            return Ok(amount);
+        }
+        let nav = if self.lp_nav == 0 {
+            (supply / Self::WIPED_OUT_SHARES_PER_UNIT).max(1)
+        } else {
+            self.lp_nav
+        };
+        let shares = amount as u128 * supply as u128 / nav as u128;
+        u64::try_from(shares).map_err(|_| ForwardError::MathOverflow.into())
+    }
+
+    /// Add a new trade of `size` to the pool
+    ///
+    /// The pool locks `pool_collateral` against it and LPs receive (or pay,
+    /// if negative) the premium; the locked collateral must stay backed by
+    /// LP capital.
+    pub fn open(
+        &mut self,
+        direction: Direction,
+        size: u64,
+        pool_collateral: u64,
+        premium: i64,
+    ) -> Result<()> {
+        match direction {
+            Direction::Long => {
+                self.total_long_exposure = self
+                    .total_long_exposure
+                    .checked_add(size)
+                    .ok_or(ForwardError::MathOverflow)?;
+            }
+            Direction::Short => {
+                self.total_short_exposure = self
+                    .total_short_exposure
+                    .checked_add(size)
+                    .ok_or(ForwardError::MathOverflow)?;
+            }
+        }
+        self.pool_collateral = self
+            .pool_collateral
+            .checked_add(pool_collateral)
+            .ok_or(ForwardError::MathOverflow)?;
+        self.apply_lp_pnl(premium)?;
+        require!(
+            self.pool_collateral <= self.lp_nav,
+            ForwardError::InsufficientLiquidity
+        );
+        Ok(())
+    }
+
+    /// Require a vault holding `vault_amount` to cover every open position
+    /// under either outcome
+    pub fn check_solvency(&self, vault_amount: u64) -> Result<()> {
+        require!(
+            vault_amount >= self.max_liability(),
+            ForwardError::VaultInsolvent
+        );
+        Ok(())
+    }
+
+    /// Remove an exited trade of `size` from the pool
+    ///
+    /// The pool's `pool_collateral` is released, and LPs keep the user's
+    /// `released_collateral` while funding `paid_out` from the vault.
+    pub fn unwind(
+        &mut self,
+        direction: Direction,
+        size: u64,
+        pool_collateral: u64,
+        released_collateral: u64,
+        paid_out: u64,
+    ) -> Result<()> {
+        self.remove_exposure(direction, size, pool_collateral)?;
+        self.apply_lp_pnl(
+            (released_collateral as i64)
+                .checked_sub(paid_out as i64)
+                .ok_or(ForwardError::MathOverflow)?,
+        )
+    }
+
+    /// Remove a refunded position from the pool
+    ///
+    /// The user gets back their collateral and premium, so LPs return the
+    /// premium they were paid (or recover one they paid out).
+    pub fn refund(&mut self, position: &Position) -> Result<()> {
+        let pool_collateral = position
+            .size
+            .checked_sub(position.collateral_locked)
+            .ok_or(ForwardError::MathOverflow)?;
+        self.remove_exposure(position.direction, position.size, pool_collateral)?;
+        self.apply_lp_pnl(
+            position
+                .premium_paid
+                .checked_neg()
+                .ok_or(ForwardError::MathOverflow)?,
+        )
+    }
+
+    /// Take `size` off the exposure on `direction`'s side and release the
+    /// pool collateral locked against it
+    fn remove_exposure(&mut self, direction: Direction, size: u64, pool_collateral: u64) -> Result<()> {
+        match direction {
+            Direction::Long => {
+                self.total_long_exposure = self
+                    .total_long_exposure
+                    .checked_sub(size)
+                    .ok_or(ForwardError::MathOverflow)?;
+            }
+            Direction::Short => {
+                self.total_short_exposure = self
+                    .total_short_exposure
+                    .checked_sub(size)
+                    .ok_or(ForwardError::MathOverflow)?;
+            }
+        }
+        self.pool_collateral = self
+            .pool_collateral
+            .checked_sub(pool_collateral)
+            .ok_or(ForwardError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Remove a resolved position paying out `payout` from the pool
+    ///
+    /// The pool's share of the position's collateral (`size - collateral_locked`)
+    /// is released.
+    pub fn settle(&mut self, position: &Position, payout: u64) -> Result<()> {
+        let pool_collateral = position
+            .size
+            .checked_sub(position.collateral_locked)
+            .ok_or(ForwardError::MathOverflow)?;
+        self.unwind(
+            position.direction,
+            position.size,
+            pool_collateral,
+            position.collateral_locked,
+            payout,
+        )
+    }
+
+    /// Credit (or debit, if negative) the LP net asset value
+    pub fn apply_lp_pnl(&mut self, pnl: i64) -> Result<()> {
+        self.lp_nav = if pnl >= 0 {
+            self.lp_nav
+                .checked_add(pnl as u64)
+                .ok_or(ForwardError::MathOverflow)?
+        } else {
+            self.lp_nav
+                .checked_sub(pnl.unsigned_abs())
+                .ok_or(ForwardError::InsufficientLiquidity)?
+        };
+        Ok(())
+    }
 }
 
 /// Individual user position
@@ -61,12 +336,197 @@ pub struct Position {
     pub forward_price: u64,     // K (stored as basis points, e.g., 5000 = 0.5)
     pub collateral_locked: u64, // User collateral
     pub premium_paid: i64,      // Can be negative if user received premium
+    pub fee_paid: u64,          // Trading fee paid to open the current size
     pub status: PositionStatus,
     pub bump: u8,
 }
 
 impl Position {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+}
+
+/// Front-end or integrator that earns a share of the trading fee on flow it refers
+#[account]
+pub struct Referrer {
+    pub authority: Pubkey, // Claims the accrued fees
+    pub tier: u8,          // Index into GlobalConfig.referral_tiers, set by the admin
+    pub accrued: u64,      // Total fees credited
+    pub claimed: u64,      // Total fees claimed
+    pub bump: u8,
+}
+
+impl Referrer {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 8 + 1;
+
+    /// Credited fees not yet claimed
+    pub fn claimable(&self) -> Result<u64> {
+        self.accrued
+            .checked_sub(self.claimed)
+            .ok_or(ForwardError::MathOverflow.into())
+    }
+}
+
+/// Running totals for the fee vault
+///
+/// Kept out of GlobalConfig so trades that credit a referrer do not
+/// write-lock the global config, which every instruction reads.
+#[account]
+pub struct FeeLedger {
+    pub referral_fees_owed: u64, // Credited to referrers but not yet claimed from the fee vault
+    pub bump: u8,
+}
+
+impl FeeLedger {
+    pub const LEN: usize = 8 + 8 + 1;
+
+    /// Fees in a vault holding `fee_vault_amount` that belong to the protocol
+    pub fn protocol_fees(&self, fee_vault_amount: u64) -> u64 {
+        fee_vault_amount.saturating_sub(self.referral_fees_owed)
+    }
+}
+
+/// Preview of opening (or increasing by) `size`, returned by `quote_open`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Quote {
+    pub forward_price: u64,   // K averaged over the trade's exposure path (basis points)
+    pub premium: i64,         // Positive: user pays, negative: user receives
+    pub user_collateral: u64, // Collateral the user locks
+    pub pool_collateral: u64, // Collateral the pool locks (Q - user collateral)
+    pub max_size: u64,        // Largest size the risk limits allow in this direction (quote_open: that also fits the pool)
+    pub fee: u64,             // Trading fee paid to the fee vault, on top of collateral and premium
+}
+
+impl Quote {
+    /// Check the user's order limits against this quote
+    ///
+    /// `slippage_tolerance` bounds the distance of K from the oracle price,
+    /// `limit_forward_price` is a max K for longs and a min K for shorts, and
+    /// `max_premium` caps the premium paid (a negative cap requires receiving
+    /// at least that much).
+    pub fn check_limits(
+        &self,
+        polymarket_price: u64,
+        direction: Direction,
+        size: u64,
+        slippage_tolerance: Option<u64>,
+        limit_forward_price: Option<u64>,
+        max_premium: Option<i64>,
+    ) -> Result<()> {
+        if let Some(slippage) = slippage_tolerance {
+            require!(
+                slippage <= BASIS_POINTS,
+                ForwardError::InvalidOracleData
+            );
+            let price_diff = self.forward_price.abs_diff(polymarket_price);
+            let slippage_bps = (price_diff * BASIS_POINTS) / polymarket_price;
+            require!(slippage_bps <= slippage, ForwardError::SlippageExceeded);
+        }
+        if let Some(limit) = limit_forward_price {
+            let within_limit = match direction {
+                Direction::Long => self.forward_price <= limit,
+                Direction::Short => self.forward_price >= limit,
+            };
+            require!(within_limit, ForwardError::LimitPriceExceeded);
+        }
+        if let Some(max_premium) = max_premium {
+            require!(self.premium <= max_premium, ForwardError::MaxPremiumExceeded);
+        }
+        require!(size <= self.max_size, ForwardError::PositionSizeExceedsLimit);
+        Ok(())
+    }
+}
+
+/// Reject orders that sat in the mempool past the user's deadline
+///
+/// `expires_at` is the last unix time the order may execute at; orders
+/// without one never expire.
+pub fn check_deadline(expires_at: Option<i64>, now: i64) -> Result<()> {
+    if let Some(expires_at) = expires_at {
+        require!(now <= expires_at, ForwardError::OrderExpired);
+    }
+    Ok(())
+}
+
+/// Preview of closing (or reducing by) `size`, returned by `quote_close`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CloseQuote {
+    pub exit_price: u64,          // Exit K averaged over the unwound exposure path (basis points)
+    pub payout: u64,              // Mark-to-market value of the exited size
+    pub fee: u64,                 // Trading fee charged, capped at the payout
+    pub user_payout: u64,         // Paid to the user: payout - fee
+    pub released_collateral: u64, // User collateral released for the exited size
+    pub pool_collateral: u64,     // Pool collateral released for the exited size
+}
+
+/// M-of-N multisig whose signer PDA can hold the admin or any role
+#[account]
+pub struct Multisig {
+    pub members: Vec<Pubkey>, // Max MAX_MEMBERS
+    pub threshold: u8,        // Approvals required to execute a proposal
+    pub proposal_count: u64,  // Counter for unique proposal IDs
+    pub config_version: u64,  // Bumped by update_multisig; older proposals go stale
+    pub signer_bump: u8,      // Bump of the [b"multisig_signer", multisig] PDA
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub const MAX_MEMBERS: usize = 10;
+    pub const LEN: usize = 8 + 4 + 32 * Self::MAX_MEMBERS + 1 + 8 + 8 + 1 + 1;
+
+    /// Validate a member set and threshold: 1..=MAX_MEMBERS distinct members
+    /// and 1 <= threshold <= members
+    pub fn validate_config(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= Self::MAX_MEMBERS,
+            ForwardError::InvalidMultisigConfig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            ForwardError::InvalidMultisigConfig
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                !members[..i].contains(member),
+                ForwardError::InvalidMultisigConfig
+            );
+        }
+        Ok(())
+    }
+
+    pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|member| member == key)
+    }
+}
+
+/// Forward program instruction awaiting multisig approval
+#[account]
+pub struct Proposal {
+    pub multisig: Pubkey,
+    pub proposer: Pubkey,
+    pub accounts: Vec<ProposalAccount>, // Account metas of the instruction
+    pub data: Vec<u8>,                  // Serialized instruction data
+    pub approvals: Vec<bool>,           // One flag per multisig member
+    pub config_version: u64,            // Multisig config_version at creation
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub fn space(accounts_len: usize, data_len: usize, members_len: usize) -> usize {
+        8 + 32 + 32 + 4 + accounts_len * ProposalAccount::LEN + 4 + data_len + 4 + members_len + 8 + 1 + 1
+    }
+}
+
+/// Account meta stored in a proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ProposalAccount {
+    pub const LEN: usize = 32 + 1 + 1;
 }
 
 /// Curve parameters for pAMM pricing
@@ -77,10 +537,182 @@ pub struct CurveParams {
     pub max_exposure: u64, // E_max: maximum allowed absolute exposure
     pub min_price: u64,    // p_min: minimum forward price (basis points, e.g., 500 = 0.05)
     pub max_price: u64,    // p_max: maximum forward price (basis points, e.g., 9500 = 0.95)
+    pub kind: CurveKind,   // Shape of the price and premium curves
+    pub steepness: u64,    // k for Tanh/Exponential (basis points, e.g., 30000 = 3.0)
+    pub breakpoint_count: u8, // Number of breakpoints used by Piecewise
+    pub breakpoints: [CurveBreakpoint; CurveParams::MAX_BREAKPOINTS],
 }
 
 impl CurveParams {
-    pub const LEN: usize = 8 + 8 + 8 + 8 + 8;
+    pub const MAX_BREAKPOINTS: usize = 8;
+    pub const MAX_STEEPNESS: u64 = 10 * BASIS_POINTS;
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 1 + 8 + 1 + CurveBreakpoint::LEN * Self::MAX_BREAKPOINTS;
+
+    /// Validate against ARCHITECTURE.md:
+    /// 0 <= p_min <= p_max <= 1 (in basis points) and non‑degenerate exposure,
+    /// plus the shape constraints of the selected curve kind.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_price <= self.max_price,
+            ForwardError::InvalidOracleData
+        );
+        require!(
+            self.max_price <= BASIS_POINTS,
+            ForwardError::InvalidOracleData
+        );
+        require!(
+            self.max_exposure > 0,
+            ForwardError::InvalidOracleData
+        );
+
+        match self.kind {
+            CurveKind::Linear => {}
+            CurveKind::Tanh | CurveKind::Exponential => {
+                require!(
+                    self.steepness > 0 && self.steepness <= Self::MAX_STEEPNESS,
+                    ForwardError::InvalidOracleData
+                );
+            }
+            CurveKind::Piecewise => {
+                let count = self.breakpoint_count as usize;
+                require!(
+                    count > 0 && count <= Self::MAX_BREAKPOINTS,
+                    ForwardError::InvalidOracleData
+                );
+
+                // Breakpoints start after the implicit (0, 0) origin, rise
+                // monotonically and end exactly at full exposure
+                let mut previous = CurveBreakpoint { exposure: 0, value: 0 };
+                for point in &self.breakpoints[..count] {
+                    require!(
+                        point.exposure > previous.exposure && point.value >= previous.value,
+                        ForwardError::InvalidOracleData
+                    );
+                    require!(
+                        point.value <= BASIS_POINTS,
+                        ForwardError::InvalidOracleData
+                    );
+                    previous = *point;
+                }
+                require!(
+                    previous.exposure == BASIS_POINTS,
+                    ForwardError::InvalidOracleData
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Curve family used to map the exposure ratio e / E_max onto the price and premium curves
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum CurveKind {
+    Linear,      // s(x) = x
+    Tanh,        // s(x) = tanh(k·x) / tanh(k), saturates at high exposure
+    Exponential, // s(x) = (e^(k·x) − 1) / (e^k − 1), steepens at high exposure
+    Piecewise,   // s(x) interpolated linearly between breakpoints
+}
+
+/// Point on a piecewise-linear curve: s(exposure) = value, both in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct CurveBreakpoint {
+    pub exposure: u64, // |e| / E_max (basis points)
+    pub value: u64,    // s(x) (basis points of alpha / beta)
+}
+
+impl CurveBreakpoint {
+    pub const LEN: usize = 8 + 8;
+}
+
+/// Shorter curve timelock queued behind the current one
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingCurveUpdateDelay {
+    pub curve_update_delay: i64,
+    pub activates_at: i64,
+}
+
+impl PendingCurveUpdateDelay {
+    pub const LEN: usize = 8 + 8;
+}
+
+/// Curve parameters queued behind the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingCurveParams {
+    pub curve_params: CurveParams,
+    pub activates_at: i64,
+}
+
+impl PendingCurveParams {
+    pub const LEN: usize = CurveParams::LEN + 8;
+
+    /// Queued parameters in `pending` whose timelock has elapsed, without applying them
+    pub fn active(pending: &Option<PendingCurveParams>, now: i64) -> Option<&CurveParams> {
+        pending
+            .as_ref()
+            .filter(|queued| now >= queued.activates_at)
+            .map(|queued| &queued.curve_params)
+    }
+
+    /// Take queued parameters out of `pending` if their timelock has elapsed
+    pub fn take_active(pending: &mut Option<PendingCurveParams>, now: i64) -> Option<CurveParams> {
+        match pending {
+            Some(queued) if now >= queued.activates_at => pending.take().map(|queued| queued.curve_params),
+            _ => None,
+        }
+    }
+}
+
+/// Per-market override change queued behind the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingMarketCurveParams {
+    pub curve_params: Option<CurveParams>, // None removes the override
+    pub activates_at: i64,
+}
+
+impl PendingMarketCurveParams {
+    pub const LEN: usize = 1 + CurveParams::LEN + 8;
+
+    /// Queued override in `pending` whose timelock has elapsed, without applying it
+    pub fn active(pending: &Option<PendingMarketCurveParams>, now: i64) -> Option<&Option<CurveParams>> {
+        pending
+            .as_ref()
+            .filter(|queued| now >= queued.activates_at)
+            .map(|queued| &queued.curve_params)
+    }
+
+    /// Take the queued override out of `pending` if its timelock has elapsed
+    pub fn take_active(pending: &mut Option<PendingMarketCurveParams>, now: i64) -> Option<Option<CurveParams>> {
+        match pending {
+            Some(queued) if now >= queued.activates_at => pending.take().map(|queued| queued.curve_params),
+            _ => None,
+        }
+    }
+}
+
+/// Optimistic resolution parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ResolutionParams {
+    pub bond_amount: u64,     // Collateral bond posted by proposers and disputers
+    pub liveness_period: i64, // Dispute window after a proposal (seconds)
+}
+
+impl ResolutionParams {
+    pub const LEN: usize = 8 + 8;
+    pub const MAX_LIVENESS_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+
+    /// A zero bond would make proposing and disputing free, and an unbounded
+    /// liveness period could overflow the dispute deadline
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.bond_amount > 0,
+            ForwardError::InvalidResolutionParams
+        );
+        require!(
+            self.liveness_period > 0 && self.liveness_period <= Self::MAX_LIVENESS_PERIOD,
+            ForwardError::InvalidResolutionParams
+        );
+        Ok(())
+    }
 }
 
 /// Risk limits per market
@@ -96,41 +728,115 @@ impl RiskLimits {
 }
 
 /// Market status
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub enum MarketStatus {
     Active,
     TradingClosed,
     Resolved,
+    Cancelled,
 }
 
 /// Position direction
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
 pub enum Direction {
     Long,
     Short,
 }
 
 /// Position status
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub enum PositionStatus {
     Open,
     Settled,
     Cancelled,
+    Closed,
 }
 
 /// Market outcome
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
 pub enum Outcome {
-    Yes, // 1
-    No,  // 0
+    Yes,        // 1
+    No,         // 0
+    Split(u64), // Fractional: YES payout fraction in basis points (e.g., 5000 = 50/50)
 }
 
 impl Outcome {
-    pub fn as_u8(&self) -> u8 {
+    /// Fraction of each contract paid to longs, in basis points
+    pub fn payout_fraction(&self) -> u64 {
         match self {
-            Outcome::Yes => 1,
+            Outcome::Yes => BASIS_POINTS,
             Outcome::No => 0,
+            Outcome::Split(fraction) => *fraction,
+        }
+    }
+
+    pub fn from_payout_fraction(fraction: u64) -> Outcome {
+        match fraction {
+            BASIS_POINTS => Outcome::Yes,
+            0 => Outcome::No,
+            _ => Outcome::Split(fraction),
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(lp_nav: u64) -> PoolState {
+        PoolState {
+            market: Pubkey::default(),
+            total_long_exposure: 0,
+            total_short_exposure: 0,
+            pool_collateral: 0,
+            position_counter: 0,
+            lp_nav,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn deposit_shares_track_nav_per_share() {
+        assert_eq!(pool(0).shares_for_deposit(5_000, 0).unwrap(), 5_000);
+        assert_eq!(pool(2_000).shares_for_deposit(500, 1_000).unwrap(), 250);
+    }
+
+    #[test]
+    fn wiped_out_pool_reprices_old_shares_to_the_floor() {
+        let supply = 10_000_000_000;
+        let floor = supply / PoolState::WIPED_OUT_SHARES_PER_UNIT;
+        let amount = 5_000_000;
+        let shares = pool(0).shares_for_deposit(amount, supply).unwrap();
+        assert_eq!(shares, amount * supply / floor);
+        // The old shares are left with the floor's worth of the new deposit
+        let old_value = amount as u128 * supply as u128 / (supply + shares) as u128;
+        assert!(old_value <= floor as u128);
+
+        // A tiny wiped-out supply floors at one base unit
+        assert_eq!(pool(0).shares_for_deposit(amount, 3).unwrap(), amount * 3);
+    }
+
+    #[test]
+    fn orders_expire_after_their_deadline() {
+        assert!(check_deadline(None, i64::MAX).is_ok());
+        assert!(check_deadline(Some(100), 99).is_ok());
+        assert!(check_deadline(Some(100), 100).is_ok());
+        assert_eq!(check_deadline(Some(100), 101).unwrap_err(), ForwardError::OrderExpired.into());
+    }
+
+    #[test]
+    fn resolution_params_need_a_bond_and_a_bounded_liveness() {
+        let params = |bond_amount, liveness_period| ResolutionParams { bond_amount, liveness_period };
+        assert!(params(1, 1).validate().is_ok());
+        assert!(params(1, ResolutionParams::MAX_LIVENESS_PERIOD).validate().is_ok());
+        for invalid in [
+            params(0, 7200),
+            params(1, 0),
+            params(1, -1),
+            params(1, ResolutionParams::MAX_LIVENESS_PERIOD + 1),
+            params(1, i64::MAX),
+        ] {
+            assert_eq!(invalid.validate().unwrap_err(), ForwardError::InvalidResolutionParams.into());
+        }
+    }
+}
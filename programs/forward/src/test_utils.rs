@@ -0,0 +1,67 @@
+//! Account fixtures for unit tests that run instruction code against
+//! hand-built `AccountInfo`s instead of a validator.
+//!
+//! Enabled for the program's own tests and, through the `test-utils`
+//! feature, for dependants such as the client's quote parity tests.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::spl_token;
+
+/// Backing storage for an `AccountInfo`
+pub struct StoredAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+impl StoredAccount {
+    /// A forward program account holding `account`
+    pub fn new<T: AccountSerialize>(account: &T) -> Self {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        Self {
+            key: Pubkey::new_unique(),
+            owner: crate::ID,
+            lamports: 1,
+            data,
+        }
+    }
+
+    /// An initialized SPL token account of `mint` owned by `owner`
+    pub fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        account.pack_into_slice(&mut data);
+        Self {
+            key: Pubkey::new_unique(),
+            owner: spl_token::ID,
+            lamports: 1,
+            data,
+        }
+    }
+
+    pub fn info(&mut self, is_writable: bool) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            false,
+            is_writable,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+}